@@ -0,0 +1,198 @@
+//! Circular-dependency (strongly-connected-component) detection.
+//!
+//! A dependency navigator's single most actionable finding is "these
+//! files form an import cycle." This module runs Tarjan's SCC algorithm,
+//! iteratively (an explicit work stack instead of recursion) to avoid
+//! stack overflow on deep import graphs, and surfaces only the
+//! nontrivial components — the ones that actually represent a cycle.
+
+/// Detects strongly-connected components (import cycles) in a directed
+/// dependency graph.
+pub struct CycleDetector {
+    num_nodes: usize,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl CycleDetector {
+    /// Create a new cycle detector from an edge list.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_nodes` - Total number of nodes in the graph
+    /// * `edges` - Slice of (source, target) directed edges
+    pub fn new(num_nodes: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacency = vec![Vec::new(); num_nodes];
+
+        for &(src, tgt) in edges {
+            if src < num_nodes && tgt < num_nodes {
+                adjacency[src].push(tgt);
+            }
+        }
+
+        Self {
+            num_nodes,
+            adjacency,
+        }
+    }
+
+    /// Find all import cycles in the graph.
+    ///
+    /// Runs Tarjan's algorithm to partition the graph into strongly
+    /// connected components, then keeps only the nontrivial ones: size
+    /// greater than one, or a single node with a self-loop. The result is
+    /// sorted largest-first, so the most sprawling cycle surfaces first.
+    ///
+    /// # Returns
+    ///
+    /// Vector of SCCs, each a `Vec<usize>` of member node IDs.
+    pub fn find_cycles(&self) -> Vec<Vec<usize>> {
+        let sccs = self.tarjan_scc();
+
+        let mut cycles: Vec<Vec<usize>> = sccs
+            .into_iter()
+            .filter(|scc| scc.len() > 1 || self.has_self_loop(scc[0]))
+            .collect();
+
+        cycles.sort_by_key(|scc| std::cmp::Reverse(scc.len()));
+        cycles
+    }
+
+    fn has_self_loop(&self, node: usize) -> bool {
+        self.adjacency[node].contains(&node)
+    }
+
+    /// Iterative Tarjan's strongly-connected-components algorithm.
+    ///
+    /// Keeps an explicit stack of `(node, next_child_index)` frames in
+    /// place of the usual recursive DFS, so deep import chains don't blow
+    /// the call stack. Each node gets a DFS index and a lowlink; when a
+    /// node's lowlink equals its own index, it roots an SCC and the
+    /// component stack is popped down to it.
+    fn tarjan_scc(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let mut index = vec![UNVISITED; self.num_nodes];
+        let mut lowlink = vec![0usize; self.num_nodes];
+        let mut on_stack = vec![false; self.num_nodes];
+        let mut stack: Vec<usize> = Vec::new();
+        let mut sccs: Vec<Vec<usize>> = Vec::new();
+        let mut next_index = 0usize;
+
+        for start in 0..self.num_nodes {
+            if index[start] != UNVISITED {
+                continue;
+            }
+
+            // Explicit work stack of (node, position in its adjacency list).
+            let mut work: Vec<(usize, usize)> = vec![(start, 0)];
+
+            while let Some(&(node, child_pos)) = work.last() {
+                if child_pos == 0 {
+                    index[node] = next_index;
+                    lowlink[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+
+                let neighbors = &self.adjacency[node];
+                if child_pos < neighbors.len() {
+                    let child = neighbors[child_pos];
+                    work.last_mut().unwrap().1 += 1;
+
+                    if index[child] == UNVISITED {
+                        work.push((child, 0));
+                    } else if on_stack[child] {
+                        lowlink[node] = lowlink[node].min(index[child]);
+                    }
+                } else {
+                    work.pop();
+                    if let Some(&(parent, _)) = work.last() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[node]);
+                    }
+
+                    if lowlink[node] == index[node] {
+                        let mut scc = Vec::new();
+                        loop {
+                            let member = stack.pop().unwrap();
+                            on_stack[member] = false;
+                            scc.push(member);
+                            if member == node {
+                                break;
+                            }
+                        }
+                        sccs.push(scc);
+                    }
+                }
+            }
+        }
+
+        sccs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_cycles_in_dag() {
+        let edges = vec![(0, 1), (1, 2), (2, 3)];
+        let detector = CycleDetector::new(4, &edges);
+
+        assert!(detector.find_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_simple_cycle() {
+        // 0 -> 1 -> 2 -> 0
+        let edges = vec![(0, 1), (1, 2), (2, 0)];
+        let detector = CycleDetector::new(3, &edges);
+
+        let cycles = detector.find_cycles();
+        assert_eq!(cycles.len(), 1);
+        let mut members = cycles[0].clone();
+        members.sort();
+        assert_eq!(members, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_self_loop_is_a_cycle() {
+        let edges = vec![(0, 0), (0, 1)];
+        let detector = CycleDetector::new(2, &edges);
+
+        let cycles = detector.find_cycles();
+        assert_eq!(cycles, vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_multiple_cycles_sorted_largest_first() {
+        // A 3-node cycle (0,1,2) and a separate 2-node cycle (3,4).
+        let edges = vec![(0, 1), (1, 2), (2, 0), (3, 4), (4, 3)];
+        let detector = CycleDetector::new(5, &edges);
+
+        let cycles = detector.find_cycles();
+        assert_eq!(cycles.len(), 2);
+        assert_eq!(cycles[0].len(), 3);
+        assert_eq!(cycles[1].len(), 2);
+    }
+
+    #[test]
+    fn test_deep_chain_does_not_overflow_stack() {
+        // A long chain with no cycles should still complete without
+        // recursing, exercising the iterative work stack.
+        let n = 10_000;
+        let edges: Vec<(usize, usize)> = (0..n - 1).map(|i| (i, i + 1)).collect();
+        let detector = CycleDetector::new(n, &edges);
+
+        assert!(detector.find_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_isolated_nodes_are_not_cycles() {
+        let edges: Vec<(usize, usize)> = vec![];
+        let detector = CycleDetector::new(4, &edges);
+
+        assert!(detector.find_cycles().is_empty());
+    }
+}
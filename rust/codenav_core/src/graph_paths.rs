@@ -0,0 +1,234 @@
+//! Shortest dependency-path queries over the import graph.
+//!
+//! Answers "how does file A end up depending on file B?" by running a
+//! breadth-first search from the source node and recording, for every
+//! reachable node, the full set of predecessors that lie on a shortest
+//! path to it. Multiple shortest paths are then enumerated by walking
+//! the predecessor sets back from the target.
+
+use hashbrown::{HashMap, HashSet};
+
+/// Finds shortest import chains between two nodes in a dependency graph.
+pub struct PathFinder {
+    num_nodes: usize,
+    adjacency: Vec<Vec<usize>>,
+    reverse_adjacency: Vec<Vec<usize>>,
+}
+
+impl PathFinder {
+    /// Create a new path finder from an edge list.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_nodes` - Total number of nodes in the graph
+    /// * `edges` - Slice of (source, target) directed edges
+    pub fn new(num_nodes: usize, edges: &[(usize, usize)]) -> Self {
+        // Dedupe per node: a parallel edge (e.g. a file importing several
+        // symbols from the same module) must not make BFS visit the same
+        // predecessor twice, which would otherwise count one shortest path
+        // multiple times in `find_paths`.
+        let mut adjacency_sets: Vec<HashSet<usize>> = vec![HashSet::new(); num_nodes];
+        let mut reverse_adjacency_sets: Vec<HashSet<usize>> = vec![HashSet::new(); num_nodes];
+
+        for &(src, tgt) in edges {
+            if src < num_nodes && tgt < num_nodes {
+                adjacency_sets[src].insert(tgt);
+                reverse_adjacency_sets[tgt].insert(src);
+            }
+        }
+
+        let adjacency = adjacency_sets
+            .into_iter()
+            .map(|set| set.into_iter().collect())
+            .collect();
+        let reverse_adjacency = reverse_adjacency_sets
+            .into_iter()
+            .map(|set| set.into_iter().collect())
+            .collect();
+
+        Self {
+            num_nodes,
+            adjacency,
+            reverse_adjacency,
+        }
+    }
+
+    /// Find the shortest import chain(s) from `source` to `target`.
+    ///
+    /// Runs a BFS from `source` over the forward adjacency list, recording
+    /// every predecessor that lies on a shortest path to each visited node.
+    /// The resulting chains are then enumerated by walking those
+    /// predecessor sets back from `target`, capped at `max_paths`.
+    ///
+    /// Returns an empty list when `target` is unreachable from `source`.
+    pub fn find_paths(&self, source: usize, target: usize, max_paths: usize) -> Vec<Vec<usize>> {
+        self.find_paths_in(&self.adjacency, source, target, max_paths)
+    }
+
+    /// Find the shortest chain(s) by which `target` transitively depends
+    /// *on* `source` (i.e. paths from `source` to `target` over the
+    /// transpose graph), used to answer "which files import this hub?".
+    pub fn find_paths_reverse(
+        &self,
+        source: usize,
+        target: usize,
+        max_paths: usize,
+    ) -> Vec<Vec<usize>> {
+        self.find_paths_in(&self.reverse_adjacency, source, target, max_paths)
+    }
+
+    fn find_paths_in(
+        &self,
+        adjacency: &[Vec<usize>],
+        source: usize,
+        target: usize,
+        max_paths: usize,
+    ) -> Vec<Vec<usize>> {
+        if max_paths == 0 || source >= self.num_nodes || target >= self.num_nodes {
+            return Vec::new();
+        }
+
+        if source == target {
+            return vec![vec![source]];
+        }
+
+        // BFS recording the shortest distance and every predecessor that
+        // achieves it, so ties can be enumerated later.
+        let mut distance: HashMap<usize, usize> = HashMap::new();
+        let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut queue: std::collections::VecDeque<usize> = std::collections::VecDeque::new();
+
+        distance.insert(source, 0);
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            let node_dist = distance[&node];
+            if node == target {
+                continue;
+            }
+            for &next in &adjacency[node] {
+                match distance.get(&next) {
+                    None => {
+                        distance.insert(next, node_dist + 1);
+                        predecessors.entry(next).or_default().push(node);
+                        queue.push_back(next);
+                    }
+                    Some(&existing) if existing == node_dist + 1 => {
+                        predecessors.entry(next).or_default().push(node);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if !distance.contains_key(&target) {
+            return Vec::new();
+        }
+
+        // Walk predecessors back from target to source, collecting
+        // shortest paths up to max_paths.
+        let mut paths = Vec::new();
+        let mut current = vec![target];
+        self.collect_paths(&predecessors, source, target, &mut current, &mut paths, max_paths);
+        paths
+    }
+
+    fn collect_paths(
+        &self,
+        predecessors: &HashMap<usize, Vec<usize>>,
+        source: usize,
+        node: usize,
+        current: &mut Vec<usize>,
+        paths: &mut Vec<Vec<usize>>,
+        max_paths: usize,
+    ) {
+        if paths.len() >= max_paths {
+            return;
+        }
+
+        if node == source {
+            let mut path = current.clone();
+            path.reverse();
+            paths.push(path);
+            return;
+        }
+
+        if let Some(preds) = predecessors.get(&node) {
+            for &prev in preds {
+                if paths.len() >= max_paths {
+                    return;
+                }
+                current.push(prev);
+                self.collect_paths(predecessors, source, prev, current, paths, max_paths);
+                current.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_chain() {
+        let edges = vec![(0, 1), (1, 2), (2, 3)];
+        let finder = PathFinder::new(4, &edges);
+
+        let paths = finder.find_paths(0, 3, 5);
+        assert_eq!(paths, vec![vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let edges = vec![(0, 1), (2, 3)];
+        let finder = PathFinder::new(4, &edges);
+
+        let paths = finder.find_paths(0, 3, 5);
+        assert!(paths.is_empty());
+    }
+
+    #[test]
+    fn test_multiple_shortest_paths_capped() {
+        // Two equally short paths from 0 to 3: via 1 and via 2.
+        let edges = vec![(0, 1), (0, 2), (1, 3), (2, 3)];
+        let finder = PathFinder::new(4, &edges);
+
+        let paths = finder.find_paths(0, 3, 1);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].len(), 3);
+
+        let all_paths = finder.find_paths(0, 3, 10);
+        assert_eq!(all_paths.len(), 2);
+    }
+
+    #[test]
+    fn test_same_source_and_target() {
+        let edges = vec![(0, 1)];
+        let finder = PathFinder::new(2, &edges);
+
+        let paths = finder.find_paths(0, 0, 5);
+        assert_eq!(paths, vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_reverse_mode() {
+        // 0 -> 1 -> 2: who transitively imports 2? Walk from 2 backwards.
+        let edges = vec![(0, 1), (1, 2)];
+        let finder = PathFinder::new(3, &edges);
+
+        let paths = finder.find_paths_reverse(2, 0, 5);
+        assert_eq!(paths, vec![vec![2, 1, 0]]);
+    }
+
+    #[test]
+    fn test_parallel_edges_do_not_duplicate_paths() {
+        // (0, 1) appears twice, as happens when a file imports several
+        // symbols from the same module; it must still count as one edge.
+        let edges = vec![(0, 1), (0, 1), (1, 2)];
+        let finder = PathFinder::new(3, &edges);
+
+        let paths = finder.find_paths(0, 2, 10);
+        assert_eq!(paths, vec![vec![0, 1, 2]]);
+    }
+}
@@ -0,0 +1,164 @@
+//! HITS (Hyperlink-Induced Topic Search) hub/authority computation.
+//!
+//! This module provides a parallel HITS implementation using Rayon,
+//! mirroring `PageRankComputer`'s construction from `num_nodes` + edges.
+
+use rayon::prelude::*;
+
+/// HITS computer distinguishing "authorities" (widely-depended-on files)
+/// from "hubs" (files that wire many modules together).
+///
+/// `HubDetector`'s in-degree score conflates these two roles; HITS scores
+/// them separately through mutual reinforcement: a node is a good
+/// authority if it's pointed to by good hubs, and a good hub if it points
+/// to good authorities.
+pub struct HitsComputer {
+    num_nodes: usize,
+    adjacency: Vec<Vec<usize>>, // outgoing edges per node
+    in_edges: Vec<Vec<usize>>,  // incoming edges per node
+}
+
+impl HitsComputer {
+    /// Create a new HITS computer.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_nodes` - Total number of nodes in the graph
+    /// * `edges` - Slice of (source, target) directed edges
+    pub fn new(num_nodes: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacency = vec![Vec::new(); num_nodes];
+        let mut in_edges = vec![Vec::new(); num_nodes];
+
+        for &(src, tgt) in edges {
+            if src < num_nodes && tgt < num_nodes {
+                adjacency[src].push(tgt);
+                in_edges[tgt].push(src);
+            }
+        }
+
+        Self {
+            num_nodes,
+            adjacency,
+            in_edges,
+        }
+    }
+
+    /// Compute authority and hub scores using power iteration.
+    ///
+    /// Each round sets `a(v) = Σ_{u→v} h(u)` (authority update over
+    /// incoming edges), then `h(v) = Σ_{v→w} a(w)` (hub update over
+    /// outgoing edges), then L2-normalizes each vector separately.
+    /// Iteration stops once the summed absolute change across both
+    /// vectors falls below `tolerance` or `max_iterations` is hit.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iterations` - Maximum number of iterations
+    /// * `tolerance` - Convergence tolerance (L1 norm over both vectors)
+    ///
+    /// # Returns
+    ///
+    /// `(authorities, hubs)`, each a vector of scores indexed by node ID.
+    pub fn compute(&self, max_iterations: usize, tolerance: f64) -> (Vec<f64>, Vec<f64>) {
+        if self.num_nodes == 0 {
+            return (Vec::new(), Vec::new());
+        }
+
+        let mut authorities: Vec<f64> = vec![1.0; self.num_nodes];
+        let mut hubs: Vec<f64> = vec![1.0; self.num_nodes];
+
+        for _iteration in 0..max_iterations {
+            let new_authorities: Vec<f64> = (0..self.num_nodes)
+                .into_par_iter()
+                .map(|v| self.in_edges[v].iter().map(|&u| hubs[u]).sum())
+                .collect();
+            let new_authorities = l2_normalize(new_authorities);
+
+            let new_hubs: Vec<f64> = (0..self.num_nodes)
+                .into_par_iter()
+                .map(|v| self.adjacency[v].iter().map(|&w| new_authorities[w]).sum())
+                .collect();
+            let new_hubs = l2_normalize(new_hubs);
+
+            let diff: f64 = authorities
+                .par_iter()
+                .zip(new_authorities.par_iter())
+                .map(|(old, new)| (old - new).abs())
+                .sum::<f64>()
+                + hubs
+                    .par_iter()
+                    .zip(new_hubs.par_iter())
+                    .map(|(old, new)| (old - new).abs())
+                    .sum::<f64>();
+
+            authorities = new_authorities;
+            hubs = new_hubs;
+
+            if diff < tolerance {
+                break;
+            }
+        }
+
+        (authorities, hubs)
+    }
+}
+
+/// L2-normalize a vector in place, returning it unchanged if its norm is 0.
+fn l2_normalize(mut values: Vec<f64>) -> Vec<f64> {
+    let norm: f64 = values.par_iter().map(|v| v * v).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        values.par_iter_mut().for_each(|v| *v /= norm);
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_graph() {
+        let edges: Vec<(usize, usize)> = vec![];
+        let computer = HitsComputer::new(0, &edges);
+        let (authorities, hubs) = computer.compute(100, 1e-9);
+
+        assert!(authorities.is_empty());
+        assert!(hubs.is_empty());
+    }
+
+    #[test]
+    fn test_isolated_nodes() {
+        let edges: Vec<(usize, usize)> = vec![];
+        let computer = HitsComputer::new(3, &edges);
+        let (authorities, hubs) = computer.compute(100, 1e-9);
+
+        assert_eq!(authorities, vec![0.0; 3]);
+        assert_eq!(hubs, vec![0.0; 3]);
+    }
+
+    #[test]
+    fn test_hub_and_authority_roles_separate() {
+        // 0 and 1 both point to 2 and 3: 0 and 1 are hubs, 2 and 3 are authorities.
+        let edges = vec![(0, 2), (0, 3), (1, 2), (1, 3)];
+        let computer = HitsComputer::new(4, &edges);
+        let (authorities, hubs) = computer.compute(100, 1e-9);
+
+        assert!(authorities[2] > authorities[0]);
+        assert!(authorities[3] > authorities[0]);
+        assert!(hubs[0] > hubs[2]);
+        assert!(hubs[1] > hubs[2]);
+    }
+
+    #[test]
+    fn test_scores_are_l2_normalized() {
+        let edges = vec![(0, 1), (0, 2), (1, 2)];
+        let computer = HitsComputer::new(3, &edges);
+        let (authorities, hubs) = computer.compute(100, 1e-9);
+
+        let auth_norm: f64 = authorities.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let hub_norm: f64 = hubs.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+        assert!((auth_norm - 1.0).abs() < 1e-6 || auth_norm == 0.0);
+        assert!((hub_norm - 1.0).abs() < 1e-6 || hub_norm == 0.0);
+    }
+}
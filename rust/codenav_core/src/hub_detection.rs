@@ -7,27 +7,48 @@ use hashbrown::HashMap;
 use rayon::prelude::*;
 
 /// Hub detector using in-degree analysis.
+///
+/// Edges may carry a weight (a file importing 8 symbols from another, or
+/// a re-export barrel, counts more than a single incidental import); the
+/// unweighted constructor is a thin wrapper that assigns every edge
+/// weight 1.0.
 pub struct HubDetector {
     num_nodes: usize,
-    in_degree: HashMap<usize, usize>,
-    out_degree: HashMap<usize, usize>,
+    in_degree: HashMap<usize, f64>,
+    out_degree: HashMap<usize, f64>,
 }
 
 impl HubDetector {
-    /// Create a new hub detector from edge list.
+    /// Create a new hub detector from an unweighted edge list.
     ///
     /// # Arguments
     ///
     /// * `num_nodes` - Total number of nodes
     /// * `edges` - Slice of (source, target) directed edges
     pub fn new(num_nodes: usize, edges: &[(usize, usize)]) -> Self {
-        let mut in_degree: HashMap<usize, usize> = HashMap::new();
-        let mut out_degree: HashMap<usize, usize> = HashMap::new();
+        let weighted: Vec<(usize, usize, f64)> =
+            edges.iter().map(|&(src, tgt)| (src, tgt, 1.0)).collect();
+        Self::from_weighted_edges(num_nodes, &weighted)
+    }
+
+    /// Create a new hub detector from a weighted edge list.
+    ///
+    /// `in_degree`/`out_degree` sum edge weights rather than counting
+    /// edges, so a heavily-weighted re-export barrel scores higher than a
+    /// single incidental import.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_nodes` - Total number of nodes
+    /// * `edges` - Slice of (source, target, weight) directed edges
+    pub fn from_weighted_edges(num_nodes: usize, edges: &[(usize, usize, f64)]) -> Self {
+        let mut in_degree: HashMap<usize, f64> = HashMap::new();
+        let mut out_degree: HashMap<usize, f64> = HashMap::new();
 
-        for &(src, tgt) in edges {
+        for &(src, tgt, weight) in edges {
             if src < num_nodes && tgt < num_nodes {
-                *out_degree.entry(src).or_insert(0) += 1;
-                *in_degree.entry(tgt).or_insert(0) += 1;
+                *out_degree.entry(src).or_insert(0.0) += weight;
+                *in_degree.entry(tgt).or_insert(0.0) += weight;
             }
         }
 
@@ -42,13 +63,13 @@ impl HubDetector {
     ///
     /// # Arguments
     ///
-    /// * `threshold` - Minimum in-degree to be considered a hub
+    /// * `threshold` - Minimum in-degree (weighted fan-in) to be considered a hub
     ///
     /// # Returns
     ///
     /// Vector of (node_index, in_degree) tuples, sorted by in-degree descending.
-    pub fn find_hubs(&self, threshold: usize) -> Vec<(usize, usize)> {
-        let mut hubs: Vec<(usize, usize)> = self
+    pub fn find_hubs(&self, threshold: f64) -> Vec<(usize, f64)> {
+        let mut hubs: Vec<(usize, f64)> = self
             .in_degree
             .iter()
             .filter(|(_, &deg)| deg >= threshold)
@@ -56,30 +77,31 @@ impl HubDetector {
             .collect();
 
         // Sort by in-degree descending
-        hubs.sort_by(|a, b| b.1.cmp(&a.1));
+        hubs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
         hubs
     }
 
-    /// Get all in-degrees as a HashMap.
-    pub fn get_in_degrees(&self) -> HashMap<usize, usize> {
+    /// Get all in-degrees (weighted fan-in) as a HashMap.
+    pub fn get_in_degrees(&self) -> HashMap<usize, f64> {
         self.in_degree.clone()
     }
 
-    /// Get all out-degrees as a HashMap.
-    pub fn get_out_degrees(&self) -> HashMap<usize, usize> {
+    /// Get all out-degrees (weighted fan-out) as a HashMap.
+    pub fn get_out_degrees(&self) -> HashMap<usize, f64> {
         self.out_degree.clone()
     }
 
     /// Compute hub scores combining in-degree and fan-out ratio.
     ///
     /// Hub score = in_degree * (1 + log(1 + out_degree))
-    /// This rewards nodes that are both imported by many and import many.
+    /// This rewards nodes that are both imported by many (weighted) and
+    /// import many (weighted).
     pub fn compute_hub_scores(&self) -> Vec<(usize, f64)> {
         let mut scores: Vec<(usize, f64)> = (0..self.num_nodes)
             .into_par_iter()
             .map(|i| {
-                let in_deg = self.in_degree.get(&i).copied().unwrap_or(0) as f64;
-                let out_deg = self.out_degree.get(&i).copied().unwrap_or(0) as f64;
+                let in_deg = self.in_degree.get(&i).copied().unwrap_or(0.0);
+                let out_deg = self.out_degree.get(&i).copied().unwrap_or(0.0);
                 let score = in_deg * (1.0 + (1.0 + out_deg).ln());
                 (i, score)
             })
@@ -98,25 +120,28 @@ impl HubDetector {
     /// - "medium" for in-degree >= 3
     /// - "low" for in-degree >= 2
     /// - "none" otherwise
-    pub fn classify_hub(in_degree: usize) -> &'static str {
+    pub fn classify_hub(in_degree: f64) -> &'static str {
         match in_degree {
-            d if d >= 8 => "critical",
-            d if d >= 5 => "high",
-            d if d >= 3 => "medium",
-            d if d >= 2 => "low",
+            d if d >= 8.0 => "critical",
+            d if d >= 5.0 => "high",
+            d if d >= 3.0 => "medium",
+            d if d >= 2.0 => "low",
             _ => "none",
         }
     }
 
     /// Get detailed hub statistics.
     pub fn get_hub_stats(&self) -> HubStats {
-        let in_degrees: Vec<usize> = self.in_degree.values().copied().collect();
+        let in_degrees: Vec<f64> = self.in_degree.values().copied().collect();
 
-        let total_hubs = in_degrees.iter().filter(|&&d| d >= 3).count();
-        let critical_hubs = in_degrees.iter().filter(|&&d| d >= 8).count();
-        let max_in_degree = in_degrees.iter().copied().max().unwrap_or(0);
+        let total_hubs = in_degrees.iter().filter(|&&d| d >= 3.0).count();
+        let critical_hubs = in_degrees.iter().filter(|&&d| d >= 8.0).count();
+        let max_in_degree = in_degrees
+            .iter()
+            .copied()
+            .fold(0.0, f64::max);
         let avg_in_degree = if !in_degrees.is_empty() {
-            in_degrees.iter().sum::<usize>() as f64 / in_degrees.len() as f64
+            in_degrees.iter().sum::<f64>() / in_degrees.len() as f64
         } else {
             0.0
         };
@@ -139,7 +164,7 @@ pub struct HubStats {
     pub nodes_with_imports: usize,
     pub total_hubs: usize,
     pub critical_hubs: usize,
-    pub max_in_degree: usize,
+    pub max_in_degree: f64,
     pub avg_in_degree: f64,
 }
 
@@ -153,19 +178,19 @@ mod tests {
         let edges = vec![(0, 3), (1, 3), (2, 3), (0, 1)];
         let detector = HubDetector::new(4, &edges);
 
-        let hubs = detector.find_hubs(3);
+        let hubs = detector.find_hubs(3.0);
         assert_eq!(hubs.len(), 1);
-        assert_eq!(hubs[0], (3, 3));
+        assert_eq!(hubs[0], (3, 3.0));
     }
 
     #[test]
     fn test_classify_hub() {
-        assert_eq!(HubDetector::classify_hub(10), "critical");
-        assert_eq!(HubDetector::classify_hub(8), "critical");
-        assert_eq!(HubDetector::classify_hub(6), "high");
-        assert_eq!(HubDetector::classify_hub(3), "medium");
-        assert_eq!(HubDetector::classify_hub(2), "low");
-        assert_eq!(HubDetector::classify_hub(1), "none");
+        assert_eq!(HubDetector::classify_hub(10.0), "critical");
+        assert_eq!(HubDetector::classify_hub(8.0), "critical");
+        assert_eq!(HubDetector::classify_hub(6.0), "high");
+        assert_eq!(HubDetector::classify_hub(3.0), "medium");
+        assert_eq!(HubDetector::classify_hub(2.0), "low");
+        assert_eq!(HubDetector::classify_hub(1.0), "none");
     }
 
     #[test]
@@ -176,8 +201,8 @@ mod tests {
         let in_deg = detector.get_in_degrees();
         let out_deg = detector.get_out_degrees();
 
-        assert_eq!(out_deg.get(&0), Some(&2));  // 0 imports 1 and 2
-        assert_eq!(in_deg.get(&2), Some(&2));   // 2 is imported by 0 and 1
+        assert_eq!(out_deg.get(&0), Some(&2.0));  // 0 imports 1 and 2
+        assert_eq!(in_deg.get(&2), Some(&2.0));   // 2 is imported by 0 and 1
     }
 
     #[test]
@@ -191,7 +216,31 @@ mod tests {
 
         assert_eq!(stats.total_nodes, 7);
         assert_eq!(stats.total_hubs, 2);  // nodes 5 and 6
-        assert_eq!(stats.max_in_degree, 5);
+        assert_eq!(stats.max_in_degree, 5.0);
+    }
+
+    #[test]
+    fn test_weighted_edges_sum_into_degrees() {
+        // Node 3 gets a heavy barrel re-export (weight 5) plus a normal import.
+        let edges = vec![(0, 3, 5.0), (1, 3, 1.0)];
+        let detector = HubDetector::from_weighted_edges(4, &edges);
+
+        let in_deg = detector.get_in_degrees();
+        assert_eq!(in_deg.get(&3), Some(&6.0));
+
+        let hubs = detector.find_hubs(6.0);
+        assert_eq!(hubs, vec![(3, 6.0)]);
+    }
+
+    #[test]
+    fn test_unweighted_constructor_matches_unit_weights() {
+        let edges = vec![(0, 3), (1, 3), (2, 3)];
+        let weighted_edges = vec![(0, 3, 1.0), (1, 3, 1.0), (2, 3, 1.0)];
+
+        let unweighted = HubDetector::new(4, &edges).get_in_degrees();
+        let weighted = HubDetector::from_weighted_edges(4, &weighted_edges).get_in_degrees();
+
+        assert_eq!(unweighted, weighted);
     }
 
     #[test]
@@ -50,6 +50,33 @@ impl ImportResolver {
         }
     }
 
+    /// Create a resolver for `fingerprint`, reusing `cache`'s persisted
+    /// `normalized_index` when the fingerprint still matches instead of
+    /// rebuilding it from `file_index` (the O(file count) loop in `new`).
+    /// Falls back to `new` on any fingerprint mismatch.
+    pub fn from_cache(
+        fingerprint: &str,
+        cache: &crate::resolver_cache::ResolverCache,
+        file_index: HashMap<String, String>,
+        extensions: Vec<String>,
+    ) -> Self {
+        if cache.fingerprint == fingerprint {
+            Self {
+                file_index,
+                extensions,
+                normalized_index: cache.normalized_index.clone(),
+            }
+        } else {
+            Self::new(file_index, extensions)
+        }
+    }
+
+    /// The fuzzy-match index built in `new`, exposed so `ResolverCache` can
+    /// persist it alongside the resolved-import map.
+    pub fn normalized_index(&self) -> &HashMap<String, Vec<String>> {
+        &self.normalized_index
+    }
+
     /// Resolve a single import string to a file path.
     ///
     /// Tries multiple strategies in order:
@@ -132,6 +159,132 @@ impl ImportResolver {
             .collect()
     }
 
+    /// Resolve an import string to every matching candidate, ranked by
+    /// confidence score instead of collapsing to a single winner.
+    ///
+    /// Each strategy that `resolve` tries in sequence (exact, extension,
+    /// directory index, normalized fuzzy, suffix) contributes its matches
+    /// here instead of short-circuiting, tagged with the `MatchKind` that
+    /// produced it. Candidates are scored by strategy first (exact highest,
+    /// suffix lowest), with a small tie-break penalty proportional to path
+    /// length and directory depth so that, within a strategy, shorter and
+    /// less-qualified paths are preferred — mirroring how a shortest-path
+    /// search favors the least-qualified route.
+    ///
+    /// # Returns
+    ///
+    /// `(path, score, kind)` triples ordered by score descending.
+    pub fn resolve_ranked(&self, import_string: &str) -> RankedMatches {
+        let normalized = normalize_import(import_string);
+        let mut candidates: RankedMatches = Vec::new();
+        let mut seen: hashbrown::HashSet<String> = hashbrown::HashSet::new();
+
+        let mut push = |path: &str, kind: MatchKind, seen: &mut hashbrown::HashSet<String>| {
+            if seen.insert(path.to_string()) {
+                candidates.push((path.to_string(), score_for(path, kind), kind));
+            }
+        };
+
+        // Strategy 1: Exact match
+        if let Some(path) = self.file_index.get(&normalized) {
+            push(path, MatchKind::Exact, &mut seen);
+        }
+
+        // Strategy 2: Try with extensions
+        for ext in &self.extensions {
+            let with_ext = format!("{}{}", normalized, ext);
+            if let Some(path) = self.file_index.get(&with_ext) {
+                push(path, MatchKind::Extension, &mut seen);
+            }
+        }
+
+        // Strategy 3: Directory index files
+        for index_name in &["index", "__init__"] {
+            for ext in &self.extensions {
+                let index_path = format!("{}/{}{}", normalized, index_name, ext);
+                if let Some(path) = self.file_index.get(&index_path) {
+                    push(path, MatchKind::DirectoryIndex, &mut seen);
+                }
+            }
+        }
+
+        // Strategy 4: Fuzzy match on normalized path
+        let normalized_lower = normalized.to_lowercase();
+        if let Some(matches) = self.normalized_index.get(&normalized_lower) {
+            for path in matches {
+                push(path, MatchKind::NormalizedFuzzy, &mut seen);
+            }
+        }
+
+        // Strategy 5: Suffix match (for imports like "utils" matching "src/utils.py")
+        for (key, path) in &self.file_index {
+            if key.ends_with(&normalized) || key.ends_with(&format!("/{}", normalized)) {
+                push(path, MatchKind::Suffix, &mut seen);
+            }
+        }
+
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        candidates
+    }
+
+    /// Resolve multiple imports in batch, returning ranked candidates for each.
+    pub fn resolve_batch_ranked(&self, imports: &[String]) -> Vec<(String, RankedMatches)> {
+        imports
+            .par_iter()
+            .map(|import| (import.clone(), self.resolve_ranked(import)))
+            .collect()
+    }
+
+    /// Resolve a `(module_string, symbol)` pair to the file that actually
+    /// defines `symbol`, following re-export/barrel chains.
+    ///
+    /// `reexports` maps a file path to the symbols it re-exports, each
+    /// paired with the module string the symbol originally came from
+    /// (e.g. an `index.js` re-exporting `{ foo } from './foo'`). After
+    /// resolving `module_string` to a file, if that file re-exports
+    /// `symbol`, the resolver follows the re-export to its origin module
+    /// and repeats, up to `MAX_REEXPORT_DEPTH` hops, guarding against
+    /// cycles with a visited set. Returns `None` if `module_string` itself
+    /// doesn't resolve.
+    pub fn resolve_symbol(
+        &self,
+        module_string: &str,
+        symbol: &str,
+        reexports: &HashMap<String, Vec<(String, String)>>,
+    ) -> Option<SymbolResolution> {
+        const MAX_REEXPORT_DEPTH: usize = 32;
+
+        let first_file = self.resolve(module_string)?;
+        let mut chain = vec![first_file.clone()];
+        let mut visited: hashbrown::HashSet<String> = hashbrown::HashSet::new();
+        visited.insert(first_file.clone());
+        let mut current_file = first_file;
+
+        for _ in 0..MAX_REEXPORT_DEPTH {
+            let Some(exports) = reexports.get(&current_file) else {
+                break;
+            };
+            let Some((_, origin_module)) = exports.iter().find(|(exported, _)| exported == symbol)
+            else {
+                break;
+            };
+            let Some(next_file) = self.resolve(origin_module) else {
+                break;
+            };
+            if !visited.insert(next_file.clone()) {
+                // Cycle detected; stop following and report where we are.
+                break;
+            }
+            chain.push(next_file.clone());
+            current_file = next_file;
+        }
+
+        Some(SymbolResolution {
+            file: current_file,
+            chain,
+        })
+    }
+
     /// Get resolution statistics for a batch of imports.
     pub fn get_resolution_stats(&self, imports: &[String]) -> ResolutionStats {
         let results = self.resolve_batch(imports);
@@ -151,6 +304,55 @@ impl ImportResolver {
     }
 }
 
+/// A ranked resolution candidate: `(path, confidence score, strategy)`.
+pub type RankedMatches = Vec<(String, f32, MatchKind)>;
+
+/// Which resolution strategy produced a ranked candidate.
+///
+/// Ordered roughly by confidence: `Exact` is the strongest signal,
+/// `Suffix` the weakest (an import like "utils" could suffix-match many
+/// unrelated files).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchKind {
+    Exact,
+    Extension,
+    DirectoryIndex,
+    NormalizedFuzzy,
+    Suffix,
+}
+
+/// Base confidence score for each strategy, before the path tie-break penalty.
+fn base_score(kind: MatchKind) -> f32 {
+    match kind {
+        MatchKind::Exact => 1.0,
+        MatchKind::Extension => 0.8,
+        MatchKind::DirectoryIndex => 0.6,
+        MatchKind::NormalizedFuzzy => 0.4,
+        MatchKind::Suffix => 0.2,
+    }
+}
+
+/// Score a candidate path, applying a small tie-break penalty proportional
+/// to path length and directory depth so shorter, less-qualified paths are
+/// preferred within the same strategy.
+fn score_for(path: &str, kind: MatchKind) -> f32 {
+    let depth = path.matches('/').count() as f32;
+    let length_penalty = path.len() as f32 * 0.0005;
+    let depth_penalty = depth * 0.001;
+    (base_score(kind) - length_penalty - depth_penalty).max(0.0)
+}
+
+/// The result of resolving a `(module, symbol)` pair, including the full
+/// chain of files hopped through to reach the terminal definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolResolution {
+    /// The file that ultimately defines the requested symbol.
+    pub file: String,
+    /// The files hopped through to get there, starting with the module's
+    /// own resolved file and ending with `file`.
+    pub chain: Vec<String>,
+}
+
 /// Statistics about import resolution.
 #[derive(Debug, Clone)]
 pub struct ResolutionStats {
@@ -283,6 +485,93 @@ mod tests {
         assert!((stats.resolution_rate - 0.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_resolve_ranked_exact_ranks_highest() {
+        let resolver = create_test_resolver();
+        let ranked = resolver.resolve_ranked("src/utils.py");
+
+        assert!(!ranked.is_empty());
+        assert_eq!(ranked[0].0, "src/utils.py");
+        assert_eq!(ranked[0].2, MatchKind::Exact);
+    }
+
+    #[test]
+    fn test_resolve_ranked_orders_by_strategy() {
+        let resolver = create_test_resolver();
+        let ranked = resolver.resolve_ranked("utils");
+
+        // "utils" is the basename of "src/utils.py", so it's already in
+        // normalized_index and hits the NormalizedFuzzy strategy (4); Suffix
+        // (5) adds nothing, since the key "src/utils.py" doesn't end with "utils".
+        assert_eq!(ranked[0].2, MatchKind::NormalizedFuzzy);
+        assert_eq!(ranked[0].0, "src/utils.py");
+    }
+
+    #[test]
+    fn test_resolve_ranked_unresolved_is_empty() {
+        let resolver = create_test_resolver();
+        let ranked = resolver.resolve_ranked("nonexistent/module");
+        assert!(ranked.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_symbol_no_reexport() {
+        let resolver = create_test_resolver();
+        let reexports = HashMap::new();
+
+        let result = resolver
+            .resolve_symbol("src/utils", "helper", &reexports)
+            .unwrap();
+        assert_eq!(result.file, "src/utils.py");
+        assert_eq!(result.chain, vec!["src/utils.py".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_symbol_follows_barrel() {
+        let resolver = create_test_resolver();
+        let mut reexports = HashMap::new();
+        reexports.insert(
+            "src/api/__init__.py".to_string(),
+            vec![("Client".to_string(), "src/api/client".to_string())],
+        );
+
+        let result = resolver
+            .resolve_symbol("src/api", "Client", &reexports)
+            .unwrap();
+        assert_eq!(result.file, "src/api/client.py");
+        assert_eq!(
+            result.chain,
+            vec!["src/api/__init__.py".to_string(), "src/api/client.py".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_resolve_symbol_guards_against_cycles() {
+        let resolver = create_test_resolver();
+        let mut reexports = HashMap::new();
+        // A barrel that (incorrectly) re-exports a symbol from itself.
+        reexports.insert(
+            "src/api/__init__.py".to_string(),
+            vec![("Client".to_string(), "src/api".to_string())],
+        );
+
+        let result = resolver
+            .resolve_symbol("src/api", "Client", &reexports)
+            .unwrap();
+        // Should terminate at the barrel rather than looping forever.
+        assert_eq!(result.file, "src/api/__init__.py");
+        assert_eq!(result.chain, vec!["src/api/__init__.py".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_symbol_unresolved_module() {
+        let resolver = create_test_resolver();
+        let reexports = HashMap::new();
+        assert!(resolver
+            .resolve_symbol("nonexistent", "anything", &reexports)
+            .is_none());
+    }
+
     #[test]
     fn test_normalize_import() {
         assert_eq!(normalize_import("./utils"), "utils");
@@ -9,6 +9,8 @@
 //!
 //! - **PageRank**: Parallel PageRank computation for dependency graphs
 //! - **Hub Detection**: Fast identification of architecturally important files
+//! - **HITS**: Separates hub and authority roles via mutual reinforcement
+//! - **Cycle Detection**: Tarjan's SCC algorithm for circular dependencies
 //! - **Import Resolution**: SIMD-accelerated string matching for imports
 //!
 //! ## Python Usage
@@ -30,14 +32,25 @@
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use rayon::prelude::*;
 
 mod pagerank;
 mod hub_detection;
+mod hits;
 mod import_resolver;
+mod graph_paths;
+mod resolver_cache;
+mod line_index;
+mod cycle_detection;
 
-use pagerank::PageRankComputer;
+use pagerank::{PageRankComputer, PageRankF64};
 use hub_detection::HubDetector;
-use import_resolver::ImportResolver;
+use hits::HitsComputer;
+use cycle_detection::CycleDetector;
+use import_resolver::{ImportResolver, MatchKind};
+use pyo3::types::PyTuple;
+use graph_paths::PathFinder;
+use line_index::LineIndex;
 
 /// Compute PageRank scores for a directed graph.
 ///
@@ -74,7 +87,7 @@ fn fast_pagerank(
 ) -> PyResult<Py<PyDict>> {
     // Release GIL during computation
     let scores = py.allow_threads(|| {
-        let computer = PageRankComputer::new(num_nodes, &edges, damping);
+        let computer: PageRankF64 = PageRankComputer::new(num_nodes, &edges, damping);
         computer.compute(max_iterations, tolerance)
     });
 
@@ -87,6 +100,103 @@ fn fast_pagerank(
     Ok(dict.into())
 }
 
+/// Compute PageRank scores along with convergence diagnostics.
+///
+/// Unlike `fast_pagerank`, which silently swallows whether it converged
+/// or hit the iteration cap, this surfaces that so a navigator UI can
+/// warn that scores are unstable on a huge graph, and callers can tune
+/// `max_iterations`/`tolerance` empirically.
+///
+/// # Arguments
+///
+/// * `num_nodes` - Total number of nodes in the graph
+/// * `edges` - List of (source, target) tuples representing directed edges
+/// * `damping` - Damping factor (default: 0.85)
+/// * `max_iterations` - Maximum iterations (default: 100)
+/// * `tolerance` - Convergence tolerance (default: 1e-6)
+///
+/// # Returns
+///
+/// Dictionary with `scores` (node index -> PageRank score), `iterations`,
+/// `converged`, and `deltas` (per-iteration L1 delta).
+#[pyfunction]
+#[pyo3(signature = (num_nodes, edges, damping=0.85, max_iterations=100, tolerance=1e-6))]
+fn fast_pagerank_with_report(
+    py: Python<'_>,
+    num_nodes: usize,
+    edges: Vec<(usize, usize)>,
+    damping: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> PyResult<Py<PyDict>> {
+    let report = py.allow_threads(|| {
+        let computer = PageRankComputer::new(num_nodes, &edges, damping);
+        computer.compute_with_report(max_iterations, tolerance)
+    });
+
+    let scores_dict = PyDict::new_bound(py);
+    for (i, score) in report.scores.iter().enumerate() {
+        scores_dict.set_item(i, *score)?;
+    }
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("scores", scores_dict)?;
+    dict.set_item("iterations", report.iterations)?;
+    dict.set_item("converged", report.converged)?;
+    dict.set_item("deltas", report.deltas)?;
+
+    Ok(dict.into())
+}
+
+/// Compute topic-sensitive (personalized) PageRank scores biased toward a
+/// seed set of files.
+///
+/// Answers "what matters relative to *this* file?" by teleporting toward
+/// `seed` instead of uniformly across all nodes.
+///
+/// # Arguments
+///
+/// * `num_nodes` - Total number of nodes in the graph
+/// * `edges` - List of (source, target) tuples representing directed edges
+/// * `seed` - Dictionary mapping node index to personalization weight
+/// * `damping` - Damping factor (default: 0.85)
+/// * `max_iterations` - Maximum iterations (default: 100)
+/// * `tolerance` - Convergence tolerance (default: 1e-6)
+///
+/// # Returns
+///
+/// Dictionary mapping node index to PageRank score.
+#[pyfunction]
+#[pyo3(signature = (num_nodes, edges, seed, damping=0.85, max_iterations=100, tolerance=1e-6))]
+fn fast_pagerank_personalized(
+    py: Python<'_>,
+    num_nodes: usize,
+    edges: Vec<(usize, usize)>,
+    seed: &Bound<'_, PyDict>,
+    damping: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> PyResult<Py<PyDict>> {
+    let mut seed_map: hashbrown::HashMap<usize, f64> = hashbrown::HashMap::new();
+    for (key, value) in seed.iter() {
+        let k: usize = key.extract()?;
+        let v: f64 = value.extract()?;
+        seed_map.insert(k, v);
+    }
+
+    let scores = py.allow_threads(|| {
+        let computer = PageRankComputer::new(num_nodes, &edges, damping);
+        computer.compute_personalized(&seed_map, max_iterations, tolerance)
+    });
+
+    let dict = PyDict::new_bound(py);
+    for (i, score) in scores.iter().enumerate() {
+        dict.set_item(i, *score)?;
+    }
+
+    Ok(dict.into())
+}
+
 /// Detect hub files based on in-degree threshold.
 ///
 /// Returns indices of nodes that have at least `threshold` incoming edges.
@@ -110,11 +220,14 @@ fn detect_hubs(
 ) -> PyResult<Py<PyList>> {
     let hubs = py.allow_threads(|| {
         let detector = HubDetector::new(num_nodes, &edges);
-        detector.find_hubs(threshold)
+        detector.find_hubs(threshold as f64)
     });
 
-    // Convert to Python list of tuples
-    let list = PyList::new_bound(py, hubs.iter().map(|(idx, degree)| (*idx, *degree)));
+    // Convert to Python list of tuples. `HubDetector` tracks weighted
+    // (f64) degrees, but this unweighted constructor only ever sums unit
+    // weights, so the result is always integral; cast back to keep this
+    // pre-existing function's Python-visible type unchanged.
+    let list = PyList::new_bound(py, hubs.iter().map(|(idx, degree)| (*idx, *degree as usize)));
 
     Ok(list.into())
 }
@@ -153,10 +266,10 @@ fn get_critical_nodes(
         let in_degrees = detector.get_in_degrees();
 
         // Combine and sort by PageRank score
-        let mut combined: Vec<(usize, f64, usize)> = scores
+        let mut combined: Vec<(usize, f64, f64)> = scores
             .iter()
             .enumerate()
-            .map(|(i, &score)| (i, score, in_degrees.get(&i).copied().unwrap_or(0)))
+            .map(|(i, &score)| (i, score, in_degrees.get(&i).copied().unwrap_or(0.0)))
             .collect();
 
         combined.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
@@ -164,14 +277,56 @@ fn get_critical_nodes(
         combined
     });
 
+    // `in_degrees` is weighted (f64), but this unweighted constructor only
+    // ever sums unit weights, so `degree` is always integral; cast back to
+    // keep this pre-existing function's Python-visible type unchanged.
     let list = PyList::new_bound(
         py,
-        results.iter().map(|(idx, score, degree)| (*idx, *score, *degree)),
+        results
+            .iter()
+            .map(|(idx, score, degree)| (*idx, *score, *degree as usize)),
     );
 
     Ok(list.into())
 }
 
+/// Compute HITS hub and authority scores for a directed graph.
+///
+/// Unlike `detect_hubs`'s static in-degree formula, HITS scores
+/// "foundational, widely-depended-on files" (high authority) and
+/// "orchestrator files that wire many modules together" (high hub)
+/// separately, through mutual reinforcement between the two roles.
+///
+/// # Arguments
+///
+/// * `num_nodes` - Total number of nodes
+/// * `edges` - List of (source, target) directed edges
+/// * `max_iterations` - Maximum iterations (default: 100)
+/// * `tolerance` - Convergence tolerance (default: 1e-9)
+///
+/// # Returns
+///
+/// Tuple of `(authorities, hubs)`, each a list of scores indexed by node.
+#[pyfunction]
+#[pyo3(signature = (num_nodes, edges, max_iterations=100, tolerance=1e-9))]
+fn compute_hits(
+    py: Python<'_>,
+    num_nodes: usize,
+    edges: Vec<(usize, usize)>,
+    max_iterations: usize,
+    tolerance: f64,
+) -> PyResult<(Py<PyList>, Py<PyList>)> {
+    let (authorities, hubs) = py.allow_threads(|| {
+        let computer = HitsComputer::new(num_nodes, &edges);
+        computer.compute(max_iterations, tolerance)
+    });
+
+    let authorities_list = PyList::new_bound(py, authorities);
+    let hubs_list = PyList::new_bound(py, hubs);
+
+    Ok((authorities_list.into(), hubs_list.into()))
+}
+
 /// Resolve multiple imports in batch using SIMD-accelerated matching.
 ///
 /// # Arguments
@@ -216,6 +371,315 @@ fn resolve_imports_batch(
     Ok(dict.into())
 }
 
+/// Resolve multiple imports in batch, returning ranked candidates instead of
+/// a single winner.
+///
+/// Unlike `resolve_imports_batch`, which collapses ambiguity by preferring
+/// the shortest path or bailing to `None`, this surfaces every candidate the
+/// resolver found so downstream tooling can show "did you mean" suggestions.
+///
+/// # Arguments
+///
+/// * `imports` - List of import strings to resolve
+/// * `file_index` - Dictionary mapping normalized paths to actual file paths
+/// * `extensions` - List of extensions to try
+///
+/// # Returns
+///
+/// Dictionary mapping import string to a list of `(path, score, kind)`
+/// tuples, ordered by score descending, where `kind` is one of "exact",
+/// "extension", "directory_index", "normalized_fuzzy", "suffix".
+#[pyfunction]
+#[pyo3(signature = (imports, file_index, extensions))]
+fn resolve_imports_ranked(
+    py: Python<'_>,
+    imports: Vec<String>,
+    file_index: &Bound<'_, PyDict>,
+    extensions: Vec<String>,
+) -> PyResult<Py<PyDict>> {
+    let mut index: hashbrown::HashMap<String, String> = hashbrown::HashMap::new();
+    for (key, value) in file_index.iter() {
+        let k: String = key.extract()?;
+        let v: String = value.extract()?;
+        index.insert(k, v);
+    }
+
+    let results = py.allow_threads(|| {
+        let resolver = ImportResolver::new(index, extensions);
+        resolver.resolve_batch_ranked(&imports)
+    });
+
+    let dict = PyDict::new_bound(py);
+    for (import, ranked) in results {
+        let candidates = PyList::new_bound(
+            py,
+            ranked
+                .iter()
+                .map(|(path, score, kind)| (path.clone(), *score, match_kind_str(*kind))),
+        );
+        dict.set_item(import, candidates)?;
+    }
+
+    Ok(dict.into())
+}
+
+/// Map a `MatchKind` to the snake_case string surfaced to Python.
+fn match_kind_str(kind: MatchKind) -> &'static str {
+    match kind {
+        MatchKind::Exact => "exact",
+        MatchKind::Extension => "extension",
+        MatchKind::DirectoryIndex => "directory_index",
+        MatchKind::NormalizedFuzzy => "normalized_fuzzy",
+        MatchKind::Suffix => "suffix",
+    }
+}
+
+/// Resolve `(module, symbol)` pairs to the file that actually defines each
+/// symbol, following re-export/barrel chains.
+///
+/// # Arguments
+///
+/// * `requests` - List of (module_string, symbol) tuples to resolve
+/// * `file_index` - Dictionary mapping normalized paths to actual file paths
+/// * `reexports` - Dictionary mapping a file path to the symbols it
+///   re-exports, each as a (symbol, origin_module_string) tuple
+/// * `extensions` - List of extensions to try
+///
+/// # Returns
+///
+/// Dictionary mapping each (module, symbol) request to a
+/// `(defining_file, chain)` tuple, or `(None, [])` if the module itself
+/// didn't resolve.
+#[pyfunction]
+#[pyo3(signature = (requests, file_index, reexports, extensions))]
+fn resolve_symbols_batch(
+    py: Python<'_>,
+    requests: Vec<(String, String)>,
+    file_index: &Bound<'_, PyDict>,
+    reexports: &Bound<'_, PyDict>,
+    extensions: Vec<String>,
+) -> PyResult<Py<PyDict>> {
+    let mut index: hashbrown::HashMap<String, String> = hashbrown::HashMap::new();
+    for (key, value) in file_index.iter() {
+        let k: String = key.extract()?;
+        let v: String = value.extract()?;
+        index.insert(k, v);
+    }
+
+    let mut reexport_map: hashbrown::HashMap<String, Vec<(String, String)>> =
+        hashbrown::HashMap::new();
+    for (key, value) in reexports.iter() {
+        let file: String = key.extract()?;
+        let entries: Vec<(String, String)> = value.extract()?;
+        reexport_map.insert(file, entries);
+    }
+
+    let results = py.allow_threads(|| {
+        let resolver = ImportResolver::new(index, extensions);
+        requests
+            .par_iter()
+            .map(|(module, symbol)| {
+                let resolution = resolver.resolve_symbol(module, symbol, &reexport_map);
+                ((module.clone(), symbol.clone()), resolution)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let dict = PyDict::new_bound(py);
+    for ((module, symbol), resolution) in results {
+        let key = PyTuple::new_bound(py, [module, symbol]);
+        match resolution {
+            Some(r) => {
+                let chain = PyList::new_bound(py, &r.chain);
+                dict.set_item(key, (r.file, chain))?;
+            }
+            None => {
+                let empty_chain = PyList::empty_bound(py);
+                dict.set_item(key, (py.None(), empty_chain))?;
+            }
+        }
+    }
+
+    Ok(dict.into())
+}
+
+/// Opaque handle to a [`LineIndex`] built for a single source file.
+///
+/// Exposed to Python as `LineIndex`; construct one via `build_line_index`.
+#[pyclass(name = "LineIndex")]
+struct PyLineIndex {
+    inner: LineIndex,
+}
+
+#[pymethods]
+impl PyLineIndex {
+    /// Convert a byte offset to a `(line, col)` position (UTF-8 byte column).
+    fn offset_to_position(&self, offset: u32) -> (u32, u32) {
+        let pos = self.inner.offset_to_position(offset);
+        (pos.line, pos.col)
+    }
+
+    /// Convert a byte offset to a `(line, col)` position with a UTF-16
+    /// code-unit column, as LSP positions require.
+    fn offset_to_position_utf16(&self, offset: u32) -> (u32, u32) {
+        let pos = self.inner.offset_to_position_utf16(offset);
+        (pos.line, pos.col)
+    }
+
+    /// Convert a `(line, col)` position (UTF-8 byte column) back to a byte offset.
+    fn position_to_offset(&self, line: u32, col: u32) -> Option<u32> {
+        self.inner.position_to_offset(line, col)
+    }
+
+    /// Convert a `(line, col)` position with a UTF-16 code-unit column back
+    /// to a byte offset.
+    fn position_to_offset_utf16(&self, line: u32, col: u32) -> Option<u32> {
+        self.inner.position_to_offset_utf16(line, col)
+    }
+
+    /// Convert many byte offsets to `(line, col)` positions in parallel.
+    fn offsets_to_positions(&self, py: Python<'_>, offsets: Vec<u32>) -> Vec<(u32, u32)> {
+        py.allow_threads(|| {
+            offsets
+                .par_iter()
+                .map(|&offset| {
+                    let pos = self.inner.offset_to_position(offset);
+                    (pos.line, pos.col)
+                })
+                .collect()
+        })
+    }
+
+    /// Number of lines in the indexed source.
+    fn line_count(&self) -> usize {
+        self.inner.line_count()
+    }
+}
+
+/// Build a line index for `source`, returning an opaque handle that can
+/// answer offset <-> `(line, col)` queries without rescanning the source.
+///
+/// # Arguments
+///
+/// * `source` - The full text of the file to index
+///
+/// # Returns
+///
+/// A `LineIndex` handle (see its methods for offset/position conversions).
+#[pyfunction]
+fn build_line_index(source: String) -> PyLineIndex {
+    PyLineIndex {
+        inner: LineIndex::new(&source),
+    }
+}
+
+/// Resolve multiple imports in batch, reusing a commit-keyed on-disk cache.
+///
+/// Rebuilding the file index and re-resolving every import from scratch is
+/// wasteful for repositories that change little between runs. This loads
+/// `cache_path`, reusing prior `(import -> resolved)` results when
+/// `fingerprint` (e.g. a git commit hash) matches what was cached, and only
+/// resolves imports absent from the cached map. A fingerprint mismatch
+/// invalidates the cache and rebuilds it from scratch.
+///
+/// # Arguments
+///
+/// * `imports` - List of import strings to resolve
+/// * `file_index` - Dictionary mapping normalized paths to actual file paths
+/// * `extensions` - List of extensions to try
+/// * `cache_path` - Path to the on-disk cache file
+/// * `fingerprint` - Caller-supplied key the cache is valid for (e.g. a git commit hash)
+///
+/// # Returns
+///
+/// Dictionary mapping import string to resolved path (or None if unresolved).
+#[pyfunction]
+#[pyo3(signature = (imports, file_index, extensions, cache_path, fingerprint))]
+fn resolve_imports_batch_cached(
+    py: Python<'_>,
+    imports: Vec<String>,
+    file_index: &Bound<'_, PyDict>,
+    extensions: Vec<String>,
+    cache_path: String,
+    fingerprint: String,
+) -> PyResult<Py<PyDict>> {
+    let mut index: hashbrown::HashMap<String, String> = hashbrown::HashMap::new();
+    for (key, value) in file_index.iter() {
+        let k: String = key.extract()?;
+        let v: String = value.extract()?;
+        index.insert(k, v);
+    }
+
+    let results = py.allow_threads(|| {
+        resolver_cache::resolve_batch_cached(
+            &imports,
+            index,
+            extensions,
+            std::path::Path::new(&cache_path),
+            &fingerprint,
+        )
+    });
+
+    let results = results
+        .map_err(|e| pyo3::exceptions::PyOSError::new_err(e.to_string()))?;
+
+    let dict = PyDict::new_bound(py);
+    for (import, resolved) in results {
+        match resolved {
+            Some(path) => dict.set_item(import, path)?,
+            None => dict.set_item(import, py.None())?,
+        }
+    }
+
+    Ok(dict.into())
+}
+
+/// Find the shortest import chain(s) between two files in the dependency graph.
+///
+/// Builds a forward adjacency list from `edges` and runs a BFS from `source`,
+/// recording predecessors, to enumerate shortest paths to `target`. Set
+/// `reverse=True` to search the transpose adjacency list instead, which
+/// answers "which files transitively import this hub?" when combined with
+/// `detect_hubs`.
+///
+/// # Arguments
+///
+/// * `num_nodes` - Total number of nodes in the graph
+/// * `edges` - List of (source, target) directed edges
+/// * `source` - Starting node index
+/// * `target` - Destination node index
+/// * `max_paths` - Maximum number of shortest paths to return
+/// * `reverse` - Traverse the transpose graph instead of the forward one
+///
+/// # Returns
+///
+/// List of node-index sequences, one per shortest path. Empty if `target`
+/// is unreachable from `source`.
+#[pyfunction]
+#[pyo3(signature = (num_nodes, edges, source, target, max_paths=10, reverse=false))]
+fn find_import_paths(
+    py: Python<'_>,
+    num_nodes: usize,
+    edges: Vec<(usize, usize)>,
+    source: usize,
+    target: usize,
+    max_paths: usize,
+    reverse: bool,
+) -> PyResult<Py<PyList>> {
+    let paths = py.allow_threads(|| {
+        let finder = PathFinder::new(num_nodes, &edges);
+        if reverse {
+            finder.find_paths_reverse(source, target, max_paths)
+        } else {
+            finder.find_paths(source, target, max_paths)
+        }
+    });
+
+    let list = PyList::new_bound(py, paths.iter().map(|path| PyList::new_bound(py, path)));
+
+    Ok(list.into())
+}
+
 /// Graph statistics computation.
 #[pyfunction]
 fn compute_graph_stats(
@@ -228,14 +692,14 @@ fn compute_graph_stats(
         let in_degrees = detector.get_in_degrees();
         let out_degrees = detector.get_out_degrees();
 
-        let total_in: usize = in_degrees.values().sum();
-        let total_out: usize = out_degrees.values().sum();
-        let max_in = in_degrees.values().max().copied().unwrap_or(0);
-        let max_out = out_degrees.values().max().copied().unwrap_or(0);
+        let total_in: f64 = in_degrees.values().sum();
+        let total_out: f64 = out_degrees.values().sum();
+        let max_in = in_degrees.values().copied().fold(0.0, f64::max);
+        let max_out = out_degrees.values().copied().fold(0.0, f64::max);
         let isolated = (0..num_nodes)
             .filter(|i| {
-                in_degrees.get(i).copied().unwrap_or(0) == 0
-                    && out_degrees.get(i).copied().unwrap_or(0) == 0
+                in_degrees.get(i).copied().unwrap_or(0.0) == 0.0
+                    && out_degrees.get(i).copied().unwrap_or(0.0) == 0.0
             })
             .count();
 
@@ -244,23 +708,69 @@ fn compute_graph_stats(
 
     let dict = PyDict::new_bound(py);
     dict.set_item("total_edges", stats.5)?;
-    dict.set_item("avg_in_degree", stats.0 as f64 / num_nodes as f64)?;
-    dict.set_item("avg_out_degree", stats.1 as f64 / num_nodes as f64)?;
-    dict.set_item("max_in_degree", stats.2)?;
-    dict.set_item("max_out_degree", stats.3)?;
+    dict.set_item("avg_in_degree", stats.0 / num_nodes as f64)?;
+    dict.set_item("avg_out_degree", stats.1 / num_nodes as f64)?;
+    // `max_in`/`max_out` are weighted (f64), but this unweighted
+    // constructor only ever sums unit weights, so they're always integral;
+    // cast back to keep this pre-existing function's Python-visible type
+    // unchanged.
+    dict.set_item("max_in_degree", stats.2 as usize)?;
+    dict.set_item("max_out_degree", stats.3 as usize)?;
     dict.set_item("isolated_nodes", stats.4)?;
 
     Ok(dict.into())
 }
 
+/// Find circular dependencies (import cycles) in a directed graph.
+///
+/// Runs Tarjan's strongly-connected-components algorithm and returns only
+/// the nontrivial components — size greater than one, or a self-loop —
+/// sorted largest-first. Pairing this with `detect_hubs`'s per-node in-
+/// degrees lets a caller flag cycles that contain a critical hub as a
+/// concrete refactor target.
+///
+/// # Arguments
+///
+/// * `num_nodes` - Total number of nodes
+/// * `edges` - List of (source, target) directed edges
+///
+/// # Returns
+///
+/// List of cycles, each a list of member node indices, largest first.
+#[pyfunction]
+fn find_cycles(
+    py: Python<'_>,
+    num_nodes: usize,
+    edges: Vec<(usize, usize)>,
+) -> PyResult<Py<PyList>> {
+    let cycles = py.allow_threads(|| {
+        let detector = CycleDetector::new(num_nodes, &edges);
+        detector.find_cycles()
+    });
+
+    let list = PyList::new_bound(py, cycles.iter().map(|cycle| PyList::new_bound(py, cycle)));
+
+    Ok(list.into())
+}
+
 /// Python module definition.
 #[pymodule]
 fn _rust_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(fast_pagerank, m)?)?;
+    m.add_function(wrap_pyfunction!(fast_pagerank_with_report, m)?)?;
+    m.add_function(wrap_pyfunction!(fast_pagerank_personalized, m)?)?;
     m.add_function(wrap_pyfunction!(detect_hubs, m)?)?;
     m.add_function(wrap_pyfunction!(get_critical_nodes, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_hits, m)?)?;
     m.add_function(wrap_pyfunction!(resolve_imports_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(resolve_imports_ranked, m)?)?;
+    m.add_function(wrap_pyfunction!(resolve_imports_batch_cached, m)?)?;
+    m.add_function(wrap_pyfunction!(build_line_index, m)?)?;
+    m.add_function(wrap_pyfunction!(resolve_symbols_batch, m)?)?;
+    m.add_class::<PyLineIndex>()?;
+    m.add_function(wrap_pyfunction!(find_import_paths, m)?)?;
     m.add_function(wrap_pyfunction!(compute_graph_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(find_cycles, m)?)?;
 
     // Version info
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
@@ -0,0 +1,261 @@
+//! Byte-offset <-> line/column conversion.
+//!
+//! A code navigator that resolves imports and ranks files ultimately needs
+//! to point users at exact source locations. `LineIndex` is built once per
+//! file by scanning for line-start offsets, then answers offset-to-position
+//! and position-to-offset queries via binary search, in both UTF-8 byte
+//! columns and UTF-16 code-unit columns (the latter needed by LSP-style
+//! consumers, which count columns in UTF-16 code units).
+
+/// A multi-byte character recorded relative to the start of its line:
+/// where it starts, how many UTF-8 bytes it occupies, and how many UTF-16
+/// code units it occupies (1 for the BMP, 2 for characters requiring a
+/// surrogate pair).
+struct MultibyteChar {
+    byte_offset: u32,
+    byte_len: u32,
+    utf16_len: u32,
+}
+
+/// Maps byte offsets to `(line, column)` positions and back for a single
+/// source file.
+pub struct LineIndex {
+    /// Byte offset of the start of each line (line 0 starts at offset 0).
+    line_starts: Vec<u32>,
+    /// Per-line, the multi-byte characters on that line in order, used to
+    /// translate between UTF-8 byte columns and UTF-16 code-unit columns.
+    multibyte_chars: Vec<Vec<MultibyteChar>>,
+    source_len: u32,
+}
+
+/// A `(line, column)` position. Both are zero-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+impl LineIndex {
+    /// Build a line index by scanning `source` for line-start offsets.
+    ///
+    /// `\r\n` is treated as a single line break; the line start is the
+    /// offset right after the `\n`, so the `\r` stays on the preceding
+    /// line like most editors display it.
+    pub fn new(source: &str) -> Self {
+        let bytes = source.as_bytes();
+        let mut line_starts = vec![0u32];
+        for (i, &byte) in bytes.iter().enumerate() {
+            if byte == b'\n' {
+                line_starts.push((i + 1) as u32);
+            }
+        }
+
+        let mut multibyte_chars: Vec<Vec<MultibyteChar>> =
+            (0..line_starts.len()).map(|_| Vec::new()).collect();
+
+        for (byte_offset, ch) in source.char_indices() {
+            let byte_len = ch.len_utf8() as u32;
+            if byte_len > 1 {
+                let offset = byte_offset as u32;
+                let line = match line_starts.binary_search(&offset) {
+                    Ok(idx) => idx,
+                    Err(idx) => idx - 1,
+                };
+                multibyte_chars[line].push(MultibyteChar {
+                    byte_offset: offset - line_starts[line],
+                    byte_len,
+                    utf16_len: ch.len_utf16() as u32,
+                });
+            }
+        }
+
+        Self {
+            line_starts,
+            multibyte_chars,
+            source_len: bytes.len() as u32,
+        }
+    }
+
+    /// Total number of lines in the source.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Convert a byte offset into a `(line, col)` position, where `col` is
+    /// a UTF-8 byte column within the line.
+    ///
+    /// Offsets past the end of the source clamp to the last valid position.
+    pub fn offset_to_position(&self, offset: u32) -> LineCol {
+        let offset = offset.min(self.source_len);
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(idx) => idx,
+            Err(idx) => idx - 1,
+        };
+        let col = offset - self.line_starts[line];
+        LineCol {
+            line: line as u32,
+            col,
+        }
+    }
+
+    /// Convert a byte offset into a `(line, col)` position where `col` is a
+    /// UTF-16 code-unit column, as LSP positions require.
+    pub fn offset_to_position_utf16(&self, offset: u32) -> LineCol {
+        let pos = self.offset_to_position(offset);
+        LineCol {
+            line: pos.line,
+            col: self.byte_col_to_utf16_col(pos.line, pos.col),
+        }
+    }
+
+    /// Convert a `(line, col)` position (UTF-8 byte column) back to a byte offset.
+    ///
+    /// Returns `None` if `line` is out of range. `col` is clamped to the
+    /// length of the line.
+    pub fn position_to_offset(&self, line: u32, col: u32) -> Option<u32> {
+        let line_start = *self.line_starts.get(line as usize)?;
+        let line_end = self
+            .line_starts
+            .get(line as usize + 1)
+            .copied()
+            .unwrap_or(self.source_len);
+        Some(line_start.saturating_add(col).min(line_end))
+    }
+
+    /// Convert a `(line, col)` position with a UTF-16 code-unit column back
+    /// to a byte offset.
+    pub fn position_to_offset_utf16(&self, line: u32, utf16_col: u32) -> Option<u32> {
+        if line as usize >= self.line_starts.len() {
+            return None;
+        }
+        let byte_col = self.utf16_col_to_byte_col(line, utf16_col);
+        self.position_to_offset(line, byte_col)
+    }
+
+    /// Translate a UTF-8 byte column on `line` to a UTF-16 code-unit column
+    /// by walking the line's multi-byte characters in order, counting
+    /// ASCII runs 1:1 and substituting each multi-byte character's UTF-16
+    /// width for its (larger) UTF-8 byte width.
+    fn byte_col_to_utf16_col(&self, line: u32, byte_col: u32) -> u32 {
+        let mut utf16_col = 0u32;
+        let mut cursor = 0u32; // byte position within the line, already translated
+
+        for ch in &self.multibyte_chars[line as usize] {
+            if ch.byte_offset >= byte_col {
+                break;
+            }
+            utf16_col += ch.byte_offset - cursor; // preceding ASCII run
+            utf16_col += ch.utf16_len;
+            cursor = ch.byte_offset + ch.byte_len;
+        }
+
+        utf16_col + byte_col.saturating_sub(cursor)
+    }
+
+    /// Inverse of [`Self::byte_col_to_utf16_col`]: translate a UTF-16
+    /// code-unit column back to a UTF-8 byte column.
+    fn utf16_col_to_byte_col(&self, line: u32, utf16_col: u32) -> u32 {
+        let mut remaining = utf16_col;
+        let mut cursor = 0u32; // byte position within the line
+
+        for ch in &self.multibyte_chars[line as usize] {
+            let ascii_run = ch.byte_offset - cursor;
+            if remaining <= ascii_run {
+                return cursor + remaining;
+            }
+            remaining -= ascii_run;
+
+            if remaining < ch.utf16_len {
+                // The requested column falls inside this character; snap
+                // to its start rather than returning a mid-character offset.
+                return ch.byte_offset;
+            }
+            remaining -= ch.utf16_len;
+            cursor = ch.byte_offset + ch.byte_len;
+        }
+
+        cursor.saturating_add(remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_file() {
+        let index = LineIndex::new("");
+        assert_eq!(index.line_count(), 1);
+        assert_eq!(index.offset_to_position(0), LineCol { line: 0, col: 0 });
+    }
+
+    #[test]
+    fn test_single_line() {
+        let index = LineIndex::new("hello world");
+        assert_eq!(index.offset_to_position(0), LineCol { line: 0, col: 0 });
+        assert_eq!(index.offset_to_position(6), LineCol { line: 0, col: 6 });
+    }
+
+    #[test]
+    fn test_multiple_lines() {
+        let index = LineIndex::new("foo\nbar\nbaz");
+        assert_eq!(index.line_count(), 3);
+        assert_eq!(index.offset_to_position(0), LineCol { line: 0, col: 0 });
+        assert_eq!(index.offset_to_position(4), LineCol { line: 1, col: 0 });
+        assert_eq!(index.offset_to_position(8), LineCol { line: 2, col: 0 });
+        assert_eq!(index.offset_to_position(10), LineCol { line: 2, col: 2 });
+    }
+
+    #[test]
+    fn test_position_to_offset_roundtrip() {
+        let source = "foo\nbar\nbaz";
+        let index = LineIndex::new(source);
+        for offset in 0..source.len() as u32 {
+            let pos = index.offset_to_position(offset);
+            assert_eq!(index.position_to_offset(pos.line, pos.col), Some(offset));
+        }
+    }
+
+    #[test]
+    fn test_offset_at_eof() {
+        let index = LineIndex::new("foo\nbar");
+        let eof = 7u32;
+        let pos = index.offset_to_position(eof);
+        assert_eq!(pos, LineCol { line: 1, col: 3 });
+        // Offsets past EOF clamp rather than panicking.
+        assert_eq!(index.offset_to_position(100), pos);
+    }
+
+    #[test]
+    fn test_crlf_line_breaks() {
+        let index = LineIndex::new("foo\r\nbar\r\nbaz");
+        assert_eq!(index.line_count(), 3);
+        // The \r stays on the preceding line.
+        assert_eq!(index.offset_to_position(3), LineCol { line: 0, col: 3 });
+        assert_eq!(index.offset_to_position(5), LineCol { line: 1, col: 0 });
+    }
+
+    #[test]
+    fn test_utf16_column_for_multibyte_line() {
+        // "héllo": 'é' is a 2-byte UTF-8 char but a single UTF-16 unit, so
+        // byte and UTF-16 columns diverge after it.
+        let index = LineIndex::new("héllo");
+        let byte_offset_of_second_l = "h\u{e9}l".len() as u32; // after h, é, l
+        let pos_utf16 = index.offset_to_position_utf16(byte_offset_of_second_l);
+        assert_eq!(pos_utf16, LineCol { line: 0, col: 3 });
+
+        let back = index
+            .position_to_offset_utf16(0, 3)
+            .expect("valid position");
+        assert_eq!(back, byte_offset_of_second_l);
+    }
+
+    #[test]
+    fn test_position_to_offset_clamps_huge_column() {
+        // An out-of-range `col`/`utf16_col` (e.g. from a malformed caller)
+        // should clamp to the end of the line rather than overflow-panic.
+        let index = LineIndex::new("foo\nbar\nbaz");
+        assert_eq!(index.position_to_offset(1, u32::MAX), Some(8));
+        assert_eq!(index.position_to_offset_utf16(1, u32::MAX), Some(8));
+    }
+}
@@ -3,36 +3,88 @@
 //! This module provides a high-performance PageRank implementation
 //! using Rayon for parallel computation across multiple CPU cores.
 
+use std::iter::Sum;
+
 use hashbrown::HashMap;
+use num_traits::Float;
 use rayon::prelude::*;
 
 /// PageRank computer with configurable damping factor.
-pub struct PageRankComputer {
+///
+/// Generic over the score scalar `F` (following petgraph's `UnitMeasure`
+/// approach), so callers on very large graphs can pick `f32` and halve the
+/// memory held by the `scores`/`new_scores` buffers; `f64` remains the
+/// default for callers that don't care. The power-iteration math is
+/// identical either way — only the precision changes.
+///
+/// Edges may carry a weight (e.g. a file that imports 8 symbols from
+/// another counts more than a single incidental import); the unweighted
+/// constructor is a thin wrapper that assigns every edge weight 1.0.
+pub struct PageRankComputer<F: Float + Send + Sync + Sum = f64> {
     num_nodes: usize,
-    adjacency: Vec<Vec<usize>>,  // outgoing edges per node
-    in_edges: Vec<Vec<usize>>,   // incoming edges per node
-    out_degree: Vec<usize>,
-    damping: f64,
+    adjacency: Vec<Vec<usize>>,        // outgoing edges per node
+    in_edges: Vec<Vec<(usize, F)>>,    // (source, weight) incoming edges per node
+    out_degree: Vec<F>,                // weighted out-degree per node
+    damping: F,
 }
 
-impl PageRankComputer {
-    /// Create a new PageRank computer.
+/// The original `f64`-scored computer, kept as an alias so existing code
+/// that names the type explicitly keeps compiling unchanged.
+pub type PageRankF64 = PageRankComputer<f64>;
+
+/// Diagnostics from a power-iteration run, for callers that need to know
+/// whether `compute` actually converged rather than just hitting the
+/// iteration cap (e.g. a navigator UI warning that scores are unstable
+/// on a huge graph).
+#[derive(Debug, Clone)]
+pub struct PageRankReport<F: Float + Send + Sync + Sum = f64> {
+    /// Final PageRank scores indexed by node ID.
+    pub scores: Vec<F>,
+    /// Number of power-iteration rounds actually run.
+    pub iterations: usize,
+    /// Whether the L1 delta dropped below `tolerance` before `max_iterations`.
+    pub converged: bool,
+    /// The L1 delta between successive score vectors, one entry per
+    /// iteration run, in order.
+    pub deltas: Vec<F>,
+}
+
+impl<F: Float + Send + Sync + Sum> PageRankComputer<F> {
+    /// Create a new PageRank computer over an unweighted edge list.
     ///
     /// # Arguments
     ///
     /// * `num_nodes` - Total number of nodes in the graph
     /// * `edges` - Slice of (source, target) directed edges
     /// * `damping` - Damping factor (typically 0.85)
-    pub fn new(num_nodes: usize, edges: &[(usize, usize)], damping: f64) -> Self {
+    pub fn new(num_nodes: usize, edges: &[(usize, usize)], damping: F) -> Self {
+        let one = F::from(1.0).unwrap();
+        let weighted: Vec<(usize, usize, F)> =
+            edges.iter().map(|&(src, tgt)| (src, tgt, one)).collect();
+        Self::from_weighted_edges(num_nodes, &weighted, damping)
+    }
+
+    /// Create a new PageRank computer over a weighted edge list.
+    ///
+    /// `out_degree[src]` accumulates the summed edge weight, and each
+    /// incoming contribution during power iteration becomes
+    /// `scores[j] * w_{j->i} / weighted_out_degree[j]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_nodes` - Total number of nodes in the graph
+    /// * `edges` - Slice of (source, target, weight) directed edges
+    /// * `damping` - Damping factor (typically 0.85)
+    pub fn from_weighted_edges(num_nodes: usize, edges: &[(usize, usize, F)], damping: F) -> Self {
         let mut adjacency = vec![Vec::new(); num_nodes];
-        let mut in_edges = vec![Vec::new(); num_nodes];
-        let mut out_degree = vec![0usize; num_nodes];
+        let mut in_edges: Vec<Vec<(usize, F)>> = vec![Vec::new(); num_nodes];
+        let mut out_degree = vec![F::zero(); num_nodes];
 
-        for &(src, tgt) in edges {
+        for &(src, tgt, weight) in edges {
             if src < num_nodes && tgt < num_nodes {
                 adjacency[src].push(tgt);
-                in_edges[tgt].push(src);
-                out_degree[src] += 1;
+                in_edges[tgt].push((src, weight));
+                out_degree[src] = out_degree[src] + weight;
             }
         }
 
@@ -53,34 +105,68 @@ impl PageRankComputer {
     /// # Arguments
     ///
     /// * `max_iterations` - Maximum number of iterations
-    /// * `tolerance` - Convergence tolerance (L1 norm)
+    /// * `tolerance` - Convergence tolerance (L1 norm), expressed in `F` so
+    ///   an `f32` computer converges at `f32` scale rather than stalling
+    ///   against an `f64`-sized tolerance.
     ///
     /// # Returns
     ///
     /// Vector of PageRank scores indexed by node ID.
-    pub fn compute(&self, max_iterations: usize, tolerance: f64) -> Vec<f64> {
+    pub fn compute(&self, max_iterations: usize, tolerance: F) -> Vec<F> {
+        self.compute_with_report(max_iterations, tolerance).scores
+    }
+
+    /// Compute PageRank scores using power iteration, also reporting
+    /// whether the run actually converged.
+    ///
+    /// Mirrors the tolerance-driven early exit in `compute`, but exposes
+    /// it so callers can tune `max_iterations`/`tolerance` empirically and
+    /// surface a "not converged" indicator instead of it being silently
+    /// swallowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iterations` - Maximum number of iterations
+    /// * `tolerance` - Convergence tolerance (L1 norm), expressed in `F` so
+    ///   an `f32` computer converges at `f32` scale rather than stalling
+    ///   against an `f64`-sized tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A `PageRankReport` with the final scores, the number of iterations
+    /// actually run, whether convergence was reached, and the per-iteration
+    /// L1 deltas.
+    pub fn compute_with_report(&self, max_iterations: usize, tolerance: F) -> PageRankReport<F> {
         if self.num_nodes == 0 {
-            return Vec::new();
+            return PageRankReport {
+                scores: Vec::new(),
+                iterations: 0,
+                converged: true,
+                deltas: Vec::new(),
+            };
         }
 
-        let n = self.num_nodes as f64;
-        let initial_score = 1.0 / n;
-        let teleport = (1.0 - self.damping) / n;
+        let zero = F::zero();
+        let one = F::one();
+        let n = F::from(self.num_nodes).unwrap();
+        let initial_score = one / n;
+        let teleport = (one - self.damping) / n;
 
-        let mut scores: Vec<f64> = vec![initial_score; self.num_nodes];
-        let mut new_scores: Vec<f64> = vec![0.0; self.num_nodes];
+        let mut scores: Vec<F> = vec![initial_score; self.num_nodes];
+        let mut new_scores: Vec<F> = vec![zero; self.num_nodes];
 
         // Handle dangling nodes (no outgoing edges)
         let dangling_nodes: Vec<usize> = (0..self.num_nodes)
-            .filter(|&i| self.out_degree[i] == 0)
+            .filter(|&i| self.out_degree[i] == zero)
             .collect();
 
+        let mut iterations = 0;
+        let mut converged = false;
+        let mut deltas: Vec<F> = Vec::new();
+
         for _iteration in 0..max_iterations {
             // Compute dangling sum (contribution from nodes with no outgoing edges)
-            let dangling_sum: f64 = dangling_nodes
-                .par_iter()
-                .map(|&i| scores[i])
-                .sum();
+            let dangling_sum: F = dangling_nodes.par_iter().map(|&i| scores[i]).sum();
             let dangling_contrib = self.damping * dangling_sum / n;
 
             // Parallel computation of new scores
@@ -88,39 +174,150 @@ impl PageRankComputer {
                 .par_iter_mut()
                 .enumerate()
                 .for_each(|(i, new_score)| {
-                    let incoming_contrib: f64 = self.in_edges[i]
+                    let incoming_contrib: F = self.in_edges[i]
                         .iter()
-                        .map(|&j| scores[j] / self.out_degree[j] as f64)
+                        .map(|&(j, weight)| scores[j] * weight / self.out_degree[j])
                         .sum();
 
                     *new_score = teleport + dangling_contrib + self.damping * incoming_contrib;
                 });
 
             // Check convergence (L1 norm)
-            let diff: f64 = scores
+            let diff: F = scores
                 .par_iter()
                 .zip(new_scores.par_iter())
-                .map(|(old, new)| (old - new).abs())
+                .map(|(old, new)| (*old - *new).abs())
                 .sum();
 
             std::mem::swap(&mut scores, &mut new_scores);
 
+            iterations += 1;
+            deltas.push(diff);
+
             if diff < tolerance {
+                converged = true;
                 break;
             }
         }
 
         // Normalize scores
-        let total: f64 = scores.iter().sum();
-        if total > 0.0 {
-            scores.par_iter_mut().for_each(|s| *s /= total);
+        let total: F = scores.iter().copied().sum();
+        if total > zero {
+            scores.par_iter_mut().for_each(|s| *s = *s / total);
+        }
+
+        PageRankReport {
+            scores,
+            iterations,
+            converged,
+            deltas,
+        }
+    }
+
+    /// Compute topic-sensitive (personalized) PageRank, biasing the
+    /// random-walk restart toward a caller-supplied set of seed nodes
+    /// instead of teleporting uniformly.
+    ///
+    /// This answers "what matters relative to *this* file?" by replacing
+    /// the uniform teleport probability `(1 - damping) / n` with
+    /// `(1 - damping) * p_i`, where `p` is `seed` normalized to sum to 1.
+    /// Dangling-node mass is likewise redistributed through `p` rather
+    /// than spread uniformly.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Map of node index to (unnormalized) personalization weight
+    /// * `max_iterations` - Maximum number of iterations
+    /// * `tolerance` - Convergence tolerance (L1 norm)
+    ///
+    /// # Returns
+    ///
+    /// Vector of PageRank scores indexed by node ID. Falls back to uniform
+    /// PageRank (equivalent to `compute`) if `seed` is empty or none of its
+    /// keys are in range.
+    pub fn compute_personalized(
+        &self,
+        seed: &HashMap<usize, F>,
+        max_iterations: usize,
+        tolerance: F,
+    ) -> Vec<F> {
+        if self.num_nodes == 0 {
+            return Vec::new();
+        }
+
+        let zero = F::zero();
+        let one = F::one();
+
+        // Drop out-of-range seeds and renormalize so the remaining weights
+        // still sum to 1, keeping the teleport mass equal to `1 - damping`.
+        let in_range_sum: F = seed
+            .iter()
+            .filter(|(&node, _)| node < self.num_nodes)
+            .map(|(_, &w)| w)
+            .sum();
+
+        let n = F::from(self.num_nodes).unwrap();
+        let personalization: Vec<F> = if seed.is_empty() || in_range_sum <= zero {
+            vec![one / n; self.num_nodes]
+        } else {
+            let mut p = vec![zero; self.num_nodes];
+            for (&node, &weight) in seed {
+                if node < self.num_nodes {
+                    p[node] = weight / in_range_sum;
+                }
+            }
+            p
+        };
+
+        let initial_score = one / n;
+        let mut scores: Vec<F> = vec![initial_score; self.num_nodes];
+        let mut new_scores: Vec<F> = vec![zero; self.num_nodes];
+
+        let dangling_nodes: Vec<usize> = (0..self.num_nodes)
+            .filter(|&i| self.out_degree[i] == zero)
+            .collect();
+
+        for _iteration in 0..max_iterations {
+            let dangling_sum: F = dangling_nodes.par_iter().map(|&i| scores[i]).sum();
+
+            new_scores
+                .par_iter_mut()
+                .enumerate()
+                .for_each(|(i, new_score)| {
+                    let incoming_contrib: F = self.in_edges[i]
+                        .iter()
+                        .map(|&(j, weight)| scores[j] * weight / self.out_degree[j])
+                        .sum();
+
+                    let teleport = (one - self.damping) * personalization[i];
+                    let dangling_contrib = self.damping * dangling_sum * personalization[i];
+
+                    *new_score = teleport + dangling_contrib + self.damping * incoming_contrib;
+                });
+
+            let diff: F = scores
+                .par_iter()
+                .zip(new_scores.par_iter())
+                .map(|(old, new)| (*old - *new).abs())
+                .sum();
+
+            std::mem::swap(&mut scores, &mut new_scores);
+
+            if diff < tolerance {
+                break;
+            }
+        }
+
+        let total: F = scores.iter().copied().sum();
+        if total > zero {
+            scores.par_iter_mut().for_each(|s| *s = *s / total);
         }
 
         scores
     }
 
     /// Compute PageRank and return as HashMap.
-    pub fn compute_as_map(&self, max_iterations: usize, tolerance: f64) -> HashMap<usize, f64> {
+    pub fn compute_as_map(&self, max_iterations: usize, tolerance: F) -> HashMap<usize, F> {
         let scores = self.compute(max_iterations, tolerance);
         scores.into_iter().enumerate().collect()
     }
@@ -176,6 +373,79 @@ mod tests {
         assert!(scores.is_empty());
     }
 
+    #[test]
+    fn test_personalized_biases_toward_seed() {
+        // Chain: 0 -> 1 -> 2 -> 3. Seeding node 0 heavily should raise
+        // its relative standing compared to uniform PageRank.
+        let edges = vec![(0, 1), (1, 2), (2, 3)];
+        let computer = PageRankComputer::new(4, &edges, 0.85);
+
+        let uniform = computer.compute(100, 1e-6);
+
+        let mut seed = HashMap::new();
+        seed.insert(0usize, 1.0);
+        let personalized = computer.compute_personalized(&seed, 100, 1e-6);
+
+        assert_eq!(personalized.len(), 4);
+        assert!(personalized[0] > uniform[0]);
+    }
+
+    #[test]
+    fn test_personalized_empty_seed_matches_uniform() {
+        let edges = vec![(0, 1), (1, 2), (2, 0)];
+        let computer = PageRankComputer::new(3, &edges, 0.85);
+
+        let uniform = computer.compute(100, 1e-6);
+        let personalized = computer.compute_personalized(&HashMap::new(), 100, 1e-6);
+
+        for (a, b) in uniform.iter().zip(personalized.iter()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_personalized_drops_out_of_range_seed() {
+        let edges = vec![(0, 1), (1, 2)];
+        let computer = PageRankComputer::new(3, &edges, 0.85);
+
+        let mut seed = HashMap::new();
+        seed.insert(99usize, 1.0); // out of range, should be dropped
+        let scores = computer.compute_personalized(&seed, 100, 1e-6);
+
+        // With no valid seed left, falls back to uniform teleport.
+        let uniform = computer.compute(100, 1e-6);
+        for (a, b) in uniform.iter().zip(scores.iter()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_weighted_edges_favor_heavier_source() {
+        // Node 0 points to both 1 and 2, but its edge to 1 is much heavier,
+        // so 0 passes 9/10 of its score to 1 and only 1/10 to 2.
+        let edges = vec![(0, 1, 9.0), (0, 2, 1.0)];
+        let computer = PageRankComputer::from_weighted_edges(3, &edges, 0.85);
+        let scores = computer.compute(100, 1e-6);
+
+        assert_eq!(scores.len(), 3);
+        assert!(scores[1] > scores[2]);
+        assert!(scores[1] > scores[0]);
+    }
+
+    #[test]
+    fn test_unweighted_constructor_matches_unit_weights() {
+        let edges = vec![(0, 1), (1, 2), (2, 0)];
+        let weighted_edges = vec![(0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0)];
+
+        let unweighted = PageRankComputer::new(3, &edges, 0.85).compute(100, 1e-6);
+        let weighted =
+            PageRankComputer::from_weighted_edges(3, &weighted_edges, 0.85).compute(100, 1e-6);
+
+        for (a, b) in unweighted.iter().zip(weighted.iter()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_isolated_nodes() {
         // No edges, just isolated nodes
@@ -189,4 +459,51 @@ mod tests {
             assert!((score - expected).abs() < 0.01);
         }
     }
+
+    #[test]
+    fn test_compute_with_report_converges() {
+        let edges = vec![(0, 1), (1, 2), (2, 0)];
+        let computer = PageRankComputer::new(3, &edges, 0.85);
+        let report = computer.compute_with_report(100, 1e-6);
+
+        assert!(report.converged);
+        assert!(report.iterations > 0);
+        assert!(report.iterations <= 100);
+        assert_eq!(report.deltas.len(), report.iterations);
+        assert_eq!(report.scores.len(), 3);
+
+        // compute() should match the report's scores exactly.
+        let plain = computer.compute(100, 1e-6);
+        assert_eq!(plain, report.scores);
+    }
+
+    #[test]
+    fn test_compute_with_report_reports_non_convergence() {
+        let edges = vec![(0, 1), (1, 2), (2, 0)];
+        let computer = PageRankComputer::new(3, &edges, 0.85);
+        // An unreachably tight tolerance forces the iteration cap.
+        let report = computer.compute_with_report(5, 0.0);
+
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 5);
+        assert_eq!(report.deltas.len(), 5);
+    }
+
+    #[test]
+    fn test_f32_computer_converges_at_f32_scale() {
+        // An f32 computer should converge using an f32-scale tolerance,
+        // halving the memory of the score buffers versus the f64 default.
+        let edges = vec![(0, 3), (1, 3), (2, 3)];
+        let computer: PageRankComputer<f32> = PageRankComputer::new(4, &edges, 0.85);
+        let scores = computer.compute(100, 1e-6);
+
+        assert_eq!(scores.len(), 4);
+        assert!(scores[3] > scores[0]);
+
+        let f64_computer = PageRankComputer::new(4, &edges, 0.85_f64);
+        let f64_scores = f64_computer.compute(100, 1e-6);
+        for (a, b) in scores.iter().zip(f64_scores.iter()) {
+            assert!((*a as f64 - b).abs() < 1e-3);
+        }
+    }
 }
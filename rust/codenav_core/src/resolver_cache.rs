@@ -0,0 +1,303 @@
+//! Persistent, commit-keyed cache for import resolution.
+//!
+//! Rebuilding the normalized index and re-resolving every import from
+//! scratch is wasteful for repositories that change little between runs.
+//! `ResolverCache` serializes the file index and prior resolution results
+//! to disk keyed by a caller-supplied fingerprint (typically a git commit
+//! hash, or a hash of the sorted file list) so later invocations only need
+//! to resolve imports that weren't seen before.
+//!
+//! The normalized fuzzy-match index is persisted too: comparing the whole
+//! `file_index` map on every call is nearly as expensive as rebuilding it
+//! from scratch, so a fingerprint match alone is trusted to mean "same
+//! files" and `ImportResolver::from_cache` reuses the stored
+//! `normalized_index` directly instead of rebuilding it.
+
+use hashbrown::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+use crate::import_resolver::ImportResolver;
+
+/// On-disk cache of a file index and its resolved imports, keyed by a
+/// caller-supplied fingerprint (e.g. a git commit hash).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolverCache {
+    pub fingerprint: String,
+    pub file_index: HashMap<String, String>,
+    pub resolved: HashMap<String, Option<String>>,
+    /// `ImportResolver`'s fuzzy-match index, persisted so a fingerprint hit
+    /// can skip rebuilding it from `file_index`.
+    pub normalized_index: HashMap<String, Vec<String>>,
+}
+
+impl ResolverCache {
+    /// Create an empty cache for a fingerprint and file index.
+    pub fn new(fingerprint: impl Into<String>, file_index: HashMap<String, String>) -> Self {
+        Self {
+            fingerprint: fingerprint.into(),
+            file_index,
+            resolved: HashMap::new(),
+            normalized_index: HashMap::new(),
+        }
+    }
+
+    /// Load a cache from disk, if present.
+    ///
+    /// Returns `Ok(None)` if the file does not exist, so callers can treat
+    /// a missing cache the same as an invalidated one.
+    pub fn load(path: &Path) -> io::Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let file = std::fs::File::open(path)?;
+        let mut lines = BufReader::new(file).lines();
+
+        let fingerprint = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(None),
+        };
+
+        let file_index_count: usize = read_count_line(&mut lines)?;
+        let mut file_index = HashMap::new();
+        for _ in 0..file_index_count {
+            let line = lines.next().transpose()?.unwrap_or_default();
+            if let Some((key, value)) = line.split_once('\t') {
+                file_index.insert(key.to_string(), value.to_string());
+            }
+        }
+
+        let resolved_count: usize = read_count_line(&mut lines)?;
+        let mut resolved = HashMap::new();
+        for _ in 0..resolved_count {
+            let line = lines.next().transpose()?.unwrap_or_default();
+            let mut parts = line.splitn(3, '\t');
+            let import = parts.next().unwrap_or_default().to_string();
+            let found = parts.next().unwrap_or("0") == "1";
+            let path = parts.next().unwrap_or_default().to_string();
+            resolved.insert(import, if found { Some(path) } else { None });
+        }
+
+        let normalized_index_count: usize = read_count_line(&mut lines)?;
+        let mut normalized_index = HashMap::new();
+        for _ in 0..normalized_index_count {
+            let line = lines.next().transpose()?.unwrap_or_default();
+            let mut parts = line.split('\t');
+            let key = parts.next().unwrap_or_default().to_string();
+            let paths: Vec<String> = parts.map(|s| s.to_string()).collect();
+            normalized_index.insert(key, paths);
+        }
+
+        Ok(Some(Self {
+            fingerprint,
+            file_index,
+            resolved,
+            normalized_index,
+        }))
+    }
+
+    /// Persist the cache to disk, overwriting any existing file.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut file = std::fs::File::create(path)?;
+
+        writeln!(file, "{}", self.fingerprint)?;
+
+        writeln!(file, "{}", self.file_index.len())?;
+        for (key, value) in &self.file_index {
+            writeln!(file, "{}\t{}", key, value)?;
+        }
+
+        writeln!(file, "{}", self.resolved.len())?;
+        for (import, resolved) in &self.resolved {
+            match resolved {
+                Some(path) => writeln!(file, "{}\t1\t{}", import, path)?,
+                None => writeln!(file, "{}\t0\t", import)?,
+            }
+        }
+
+        writeln!(file, "{}", self.normalized_index.len())?;
+        for (key, paths) in &self.normalized_index {
+            writeln!(file, "{}\t{}", key, paths.join("\t"))?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether this cache can be reused as-is for `fingerprint`.
+    ///
+    /// Trusts the fingerprint alone (it's typically a git commit hash or a
+    /// hash of the sorted file list) rather than comparing the full
+    /// `file_index` map, which would cost close to what rebuilding it costs.
+    fn is_valid_for(&self, fingerprint: &str) -> bool {
+        self.fingerprint == fingerprint
+    }
+}
+
+fn read_count_line(lines: &mut std::io::Lines<BufReader<std::fs::File>>) -> io::Result<usize> {
+    let line = lines.next().transpose()?.unwrap_or_default();
+    Ok(line.trim().parse().unwrap_or(0))
+}
+
+/// Resolve a batch of imports, reusing a commit-keyed on-disk cache.
+///
+/// Loads the cache at `cache_path`; if its fingerprint matches the one
+/// passed in, both the cached `resolved` map and the persisted
+/// `normalized_index` are reused, so only imports absent from `resolved`
+/// are actually resolved and the `ImportResolver` build skips rebuilding
+/// its fuzzy-match index. A fingerprint mismatch invalidates the cache and
+/// rebuilds everything from scratch. The (possibly updated) cache is
+/// written back to `cache_path` before returning.
+pub fn resolve_batch_cached(
+    imports: &[String],
+    file_index: HashMap<String, String>,
+    extensions: Vec<String>,
+    cache_path: &Path,
+    fingerprint: &str,
+) -> io::Result<Vec<(String, Option<String>)>> {
+    let loaded = ResolverCache::load(cache_path)?;
+    let hit = matches!(&loaded, Some(existing) if existing.is_valid_for(fingerprint));
+
+    let resolver = match &loaded {
+        Some(existing) => ImportResolver::from_cache(fingerprint, existing, file_index.clone(), extensions),
+        None => ImportResolver::new(file_index.clone(), extensions),
+    };
+
+    let mut cache = match loaded {
+        Some(existing) if hit => existing,
+        _ => ResolverCache::new(fingerprint, file_index.clone()),
+    };
+    if !hit {
+        // Either there was no cache, or the fingerprint changed: the
+        // resolver above rebuilt its fuzzy-match index from scratch, so
+        // persist that rebuilt copy alongside the new fingerprint.
+        cache.normalized_index = resolver.normalized_index().clone();
+    }
+    cache.file_index = file_index;
+
+    let results: Vec<(String, Option<String>)> = imports
+        .iter()
+        .map(|import| {
+            if let Some(cached) = cache.resolved.get(import) {
+                (import.clone(), cached.clone())
+            } else {
+                let resolved = resolver.resolve(import);
+                cache.resolved.insert(import.clone(), resolved.clone());
+                (import.clone(), resolved)
+            }
+        })
+        .collect();
+
+    cache.save(cache_path)?;
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_file_index() -> HashMap<String, String> {
+        let mut index = HashMap::new();
+        index.insert("src/utils.py".to_string(), "src/utils.py".to_string());
+        index
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("resolver_cache_test_{:p}.tmp", &dir));
+
+        let mut cache = ResolverCache::new("commit-abc123", test_file_index());
+        cache
+            .resolved
+            .insert("utils".to_string(), Some("src/utils.py".to_string()));
+        cache.resolved.insert("missing".to_string(), None);
+
+        cache.save(&path).unwrap();
+        let loaded = ResolverCache::load(&path).unwrap().unwrap();
+
+        assert_eq!(loaded.fingerprint, "commit-abc123");
+        assert_eq!(loaded.file_index, test_file_index());
+        assert_eq!(
+            loaded.resolved.get("utils"),
+            Some(&Some("src/utils.py".to_string()))
+        );
+        assert_eq!(loaded.resolved.get("missing"), Some(&None));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_none() {
+        let path = std::env::temp_dir().join("resolver_cache_does_not_exist.tmp");
+        std::fs::remove_file(&path).ok();
+        assert!(ResolverCache::load(&path).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_resolve_batch_cached_reuses_entries() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("resolver_cache_test_reuse_{:p}.tmp", &dir));
+        std::fs::remove_file(&path).ok();
+
+        let imports = vec!["src/utils".to_string()];
+        let first = resolve_batch_cached(
+            &imports,
+            test_file_index(),
+            vec![".py".to_string()],
+            &path,
+            "commit-1",
+        )
+        .unwrap();
+        assert_eq!(first[0].1, Some("src/utils.py".to_string()));
+
+        // A second call with the same fingerprint should reuse the cached result.
+        let second = resolve_batch_cached(
+            &imports,
+            test_file_index(),
+            vec![".py".to_string()],
+            &path,
+            "commit-1",
+        )
+        .unwrap();
+        assert_eq!(second[0].1, Some("src/utils.py".to_string()));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_resolve_batch_cached_invalidates_on_fingerprint_mismatch() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("resolver_cache_test_invalidate_{:p}.tmp", &dir));
+        std::fs::remove_file(&path).ok();
+
+        let imports = vec!["src/utils".to_string()];
+        resolve_batch_cached(
+            &imports,
+            test_file_index(),
+            vec![".py".to_string()],
+            &path,
+            "commit-1",
+        )
+        .unwrap();
+
+        let mut other_index = test_file_index();
+        other_index.insert("src/other.py".to_string(), "src/other.py".to_string());
+        let results = resolve_batch_cached(
+            &imports,
+            other_index.clone(),
+            vec![".py".to_string()],
+            &path,
+            "commit-2",
+        )
+        .unwrap();
+        assert_eq!(results[0].1, Some("src/utils.py".to_string()));
+
+        let reloaded = ResolverCache::load(&path).unwrap().unwrap();
+        assert_eq!(reloaded.fingerprint, "commit-2");
+        assert_eq!(reloaded.file_index, other_index);
+
+        std::fs::remove_file(&path).ok();
+    }
+}